@@ -1,13 +1,92 @@
 #![allow(unstable_features)]
-#![allow(clippy::wildcard_imports)]
 #![allow(clippy::too_many_lines)]
 
 use std::{fs::read_to_string, path::Path};
 
-use aoc::*;
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use aoc::{
+  filter::Filter,
+  puzzle::Puzzle,
+  puzzles,
+  registry::{self, input_dir, Input},
+};
+use criterion::{
+  criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup,
+  BenchmarkId, Criterion,
+};
 
-include!(concat!(env!("OUT_DIR"), "/benchmarks.rs"));
+/// Benchmarks each function required to solve the given puzzle.
+fn bench<P: Puzzle>(
+  group: &mut BenchmarkGroup<'_, WallTime>,
+  name: &str,
+  data: &str,
+) {
+  group.bench_with_input(
+    BenchmarkId::new(format!("{name}_parse"), ""),
+    data,
+    |b, d| {
+      b.iter(|| P::parse(d));
+    },
+  );
+
+  let input = P::parse(data);
+
+  group.bench_with_input(
+    BenchmarkId::new(format!("{name}_p1"), ""),
+    &input,
+    |b, i| {
+      b.iter(|| P::p1(i));
+    },
+  );
+
+  group.bench_with_input(
+    BenchmarkId::new(format!("{name}_p2"), ""),
+    &input,
+    |b, i| {
+      b.iter(|| P::p2(i));
+    },
+  );
+}
+
+/// Returns the inputs for the given puzzle within the given input directory.
+fn inputs(dir: &Path, year: u32, day: u32) -> Vec<Input> {
+  registry::get(year, day).unwrap().inputs(dir)
+}
+
+/// Generates the benchmarks for each puzzle in the registry, guarded by the
+/// year and day filters read from the `AOC_YEAR` and `AOC_DAY` environment
+/// variables. The inputs are read from the same directory as the runner's, and
+/// only the first of several named inputs is measured.
+macro_rules! benchmarks {
+  ($((
+    $year:literal,
+    $day:literal,
+    $year_mod:ident,
+    $day_mod:ident,
+    $title:literal
+  )),* $(,)?) => {
+    fn aoc_bench(c: &mut Criterion) {
+      let mut group = c.benchmark_group("aoc");
+
+      let years = Filter::from_env("AOC_YEAR").unwrap();
+      let days = Filter::from_env("AOC_DAY").unwrap();
+      let dir = input_dir();
+
+      $(
+        if years.contains($year) && days.contains($day) {
+          bench::<aoc::$year_mod::$day_mod::Solution>(
+            &mut group,
+            &format!("y{}_d{:0>2}", $year, $day),
+            &read_to_string(&inputs(&dir, $year, $day)[0].path).unwrap(),
+          );
+        }
+      )*
+
+      group.finish();
+    }
+  };
+}
+
+puzzles!(benchmarks);
 
 criterion_group!(benches, aoc_bench);
 criterion_main!(benches);
@@ -1,10 +1,11 @@
 //! This build script generates files with information on which puzzles have
-//! been attempted in the codebase.
+//! been attempted in the codebase, along with the modules that contain them.
 use std::{
   env,
-  fs::{read_dir, File},
+  fmt::Write as _,
+  fs::{read_dir, read_to_string, File},
   io::Write,
-  path::Path,
+  path::{Path, PathBuf},
 };
 
 /// Create a file with the given content
@@ -15,102 +16,86 @@ fn make_file(out_dir: &str, filename: &str, content: &str) {
     .unwrap_or_else(|_| panic!("Failed to write {filename}"));
 }
 
-/// Generate the `solvers.rs` file
+/// Extract the title of a puzzle from the first line of its module's
+/// documentation, which is of the form `//! # Title`
+fn read_title(path: &Path) -> String {
+  read_to_string(path)
+    .unwrap_or_else(|_| panic!("Failed to read {}", path.display()))
+    .lines()
+    .next()
+    .and_then(|line| line.strip_prefix("//! # "))
+    .unwrap_or_default()
+    .trim()
+    .to_string()
+}
+
+/// Generate the `puzzles.rs` file
 ///
-/// This file contains a static vector of `Solver` structs that are generated
-/// using the `solver` macro.
+/// This file contains the `puzzles` macro, which invokes the given macro with
+/// the year, day, year module, day module and title of each puzzle.
 ///
-/// It is included in the `main.rs` file.
-fn generate_solvers(out_dir: &str, puzzles: &[(u32, u32)]) {
+/// It is included in the `registry.rs` file.
+fn generate_puzzles(out_dir: &str, puzzles: &[(u32, u32, String)]) {
   let mut generated_code = String::new();
 
-  // Generate the code for a static vector of solver macro invocations
-  if !puzzles.is_empty() {
-    generated_code
-      .push_str("lazy_static! {\nstatic ref SOLVERS: Vec<Solver> = vec![\n");
-
-    for &puzzle in puzzles {
-      generated_code
-        .push_str(&format!("solver!(y{:>4}, d{:0>2}),\n", puzzle.0, puzzle.1));
-    }
-
-    generated_code.push_str("];\n}\n");
+  generated_code.push_str(
+    r"
+/// Invokes the given macro with a `(year, day, year module, day module, title)`
+/// tuple for each puzzle that has been attempted in the codebase.
+#[macro_export]
+macro_rules! puzzles {
+  ($callback:ident) => {
+    $callback! {
+    "
+    .trim(),
+  );
+  generated_code.push('\n');
+
+  for (year, day, title) in puzzles {
+    writeln!(
+      generated_code,
+      "      ({year}, {day}, y{year:>4}, d{day:0>2}, {title:?}),"
+    )
+    .unwrap();
   }
 
-  make_file(out_dir, "solvers.rs", &generated_code);
+  generated_code.push_str("    }\n  };\n}\n");
+
+  make_file(out_dir, "puzzles.rs", &generated_code);
 }
 
-/// Generate the `benchmarks.rs` file
+/// Generate the `modules.rs` file
 ///
-/// This file contains the benchmark macro invocations for each puzzle.
+/// This file declares a module for each year, containing a module for each
+/// Rust file in the year's directory, such as the days' puzzles and any
+/// helpers they share. The modules are declared with absolute paths, as the
+/// file is not located in the src directory.
 ///
-/// It is included in the `benches/benchmark.rs` file.
-fn generate_benchmarks(out_dir: &str, puzzles: &[(u32, u32)]) {
+/// It is included in the `lib.rs` file.
+fn generate_modules(out_dir: &str, modules: &[(u32, Vec<(String, PathBuf)>)]) {
   let mut generated_code = String::new();
 
-  generated_code.push_str(
-    r#"
-fn aoc_bench(c: &mut Criterion) {
-  let mut group = c.benchmark_group("aoc");
-
-  "#
-    .trim(),
-  );
+  for (year, year_modules) in modules {
+    writeln!(generated_code, "pub mod y{year} {{").unwrap();
 
-  for &(year, day) in puzzles {
-    generated_code.push_str(
-      format!(
-        r#"
-let data = read_to_string(
-  Path::new("input")
-    .join("y{year:>4}")
-    .join("d{day:0>2}")
-    .with_extension("txt"),
-).unwrap();
-
-group.bench_with_input(
-  BenchmarkId::new("y{year:>4}_d{day:0>2}_parse", ""),
-  &data,
-  |b, d| {{
-    b.iter(|| y{year:>4}::d{day:0>2}::parse(d));
-  }},
-);
-
-let input = y{year:>4}::d{day:0>2}::parse(&data);
-
-group.bench_with_input(
-  BenchmarkId::new("y{year:>4}_d{day:0>2}_p1", ""),
-  &input,
-  |b, i| {{
-    b.iter(|| y{year:>4}::d{day:0>2}::p1(i));
-  }},
-);
-
-group.bench_with_input(
-  BenchmarkId::new("y{year:>4}_d{day:0>2}_p2", ""),
-  &input,
-  |b, i| {{
-    b.iter(|| y{year:>4}::d{day:0>2}::p2(i));
-  }},
-);
-    "#
+    for (module, path) in year_modules {
+      writeln!(
+        generated_code,
+        "  #[path = {:?}]\n  pub mod {module};",
+        path.display().to_string()
       )
-      .trim(),
-    );
-  }
+      .unwrap();
+    }
 
-  generated_code.push_str(
-    r"
-  group.finish();
-}"
-    .trim(),
-  );
+    generated_code.push_str("}\n");
+  }
 
-  make_file(out_dir, "benchmarks.rs", &generated_code);
+  make_file(out_dir, "modules.rs", &generated_code);
 }
 
 fn main() {
   let mut puzzles = Vec::new();
+  let mut modules = Vec::new();
 
   // Read the src directory
   let src_dir = Path::new("src");
@@ -127,15 +112,30 @@ fn main() {
           continue;
         }
         let year = year.unwrap();
+        let mut year_modules = Vec::new();
 
         for day_entry in read_dir(&path).expect("Failed to read year directory")
         {
           let day_entry = day_entry.expect("Failed to read day entry");
           let day_path = day_entry.path();
 
+          if day_path
+            .extension()
+            .is_none_or(|extension| extension != "rs")
+          {
+            continue;
+          }
+
           if let Some(day_mod) =
             day_path.file_stem().and_then(|name| name.to_str())
           {
+            year_modules.push((
+              day_mod.to_string(),
+              day_path
+                .canonicalize()
+                .expect("Failed to resolve module path"),
+            ));
+
             // Check for those directories in the year directory that start with
             // 'd' and parse as a day
             let day = day_mod[1..].parse::<u32>();
@@ -144,17 +144,23 @@ fn main() {
             }
             let day = day.unwrap();
 
-            puzzles.push((year, day));
+            puzzles.push((year, day, read_title(&day_path)));
           }
         }
+
+        year_modules.sort();
+        modules.push((year, year_modules));
       }
     }
   }
 
+  puzzles.sort();
+  modules.sort();
+
   // Create the files
   let out_dir =
     env::var("OUT_DIR").expect("Failed to read OUT_DIR environment variable");
 
-  generate_solvers(&out_dir, &puzzles);
-  generate_benchmarks(&out_dir, &puzzles);
+  generate_puzzles(&out_dir, &puzzles);
+  generate_modules(&out_dir, &modules);
 }
@@ -0,0 +1,185 @@
+//! This module contains utilities for storing and verifying the known answers
+//! to puzzles.
+//!
+//! The answers for each year are stored in a TOML file, keyed by day:
+//!
+//! ```toml
+//! [d01]
+//! part1 = "232"
+//! part2 = "1783"
+//! ```
+//!
+//! The answers for named inputs, which are specific to an account, are stored
+//! in a separate file per input, e.g. `answers/alice/y2015.toml`.
+
+use std::{
+  collections::BTreeMap,
+  error::Error,
+  fmt,
+  fs::{create_dir_all, read_to_string, write},
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the known answers to both parts of a day's puzzle.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayAnswers {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub part1: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub part2: Option<String>,
+}
+
+/// Represents the outcome of comparing an answer against the known answer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+  Pass,
+  Fail { expected: String },
+  Unknown,
+}
+
+impl Verdict {
+  /// Compares the actual answer with the expected answer, if there is one.
+  pub fn new(expected: Option<&str>, actual: &str) -> Self {
+    match expected {
+      Some(expected) if expected == actual => Self::Pass,
+      Some(expected) => Self::Fail {
+        expected: expected.to_string(),
+      },
+      None => Self::Unknown,
+    }
+  }
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Pass => write!(f, "pass"),
+      Self::Fail { expected } => write!(f, "fail (expected {expected})"),
+      Self::Unknown => write!(f, "unknown"),
+    }
+  }
+}
+
+/// Represents the store of known answers for a year's puzzles, for either the
+/// default input or a named input.
+pub struct AnswerStore {
+  path: PathBuf,
+  days: BTreeMap<String, DayAnswers>,
+}
+
+impl AnswerStore {
+  /// Returns the path to the answers file for the given year and input.
+  pub fn path(year: u32, input: Option<&str>) -> PathBuf {
+    input
+      .map_or_else(
+        || Path::new("answers").to_path_buf(),
+        |input| Path::new("answers").join(input),
+      )
+      .join(format!("y{year}"))
+      .with_extension("toml")
+  }
+
+  /// Loads the known answers for the given year and input. If there is no
+  /// answers file for them, the store is empty.
+  pub fn load(year: u32, input: Option<&str>) -> Result<Self, Box<dyn Error>> {
+    Self::load_from(Self::path(year, input))
+  }
+
+  /// Loads the known answers from the given answers file. If it does not
+  /// exist, the store is empty.
+  pub fn load_from(path: PathBuf) -> Result<Self, Box<dyn Error>> {
+    let days = if path.exists() {
+      toml::from_str(&read_to_string(&path)?)?
+    } else {
+      BTreeMap::new()
+    };
+
+    Ok(Self { path, days })
+  }
+
+  /// Returns the known answers for the given day.
+  pub fn get(&self, day: u32) -> Option<&DayAnswers> {
+    self.days.get(&format!("d{day:0>2}"))
+  }
+
+  /// Sets the known answers for the given day.
+  pub fn set(&mut self, day: u32, answers: DayAnswers) {
+    self.days.insert(format!("d{day:0>2}"), answers);
+  }
+
+  /// Returns the path to the answers file.
+  pub fn file(&self) -> &Path {
+    &self.path
+  }
+
+  /// Writes the known answers back to the answers file.
+  pub fn save(&self) -> Result<(), Box<dyn Error>> {
+    if let Some(parent) = self.path.parent() {
+      create_dir_all(parent)?;
+    }
+
+    write(&self.path, toml::to_string(&self.days)?)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+  use tempfile::tempdir;
+
+  use super::*;
+
+  #[rstest]
+  #[case(Some("232"), "232", Verdict::Pass)]
+  #[case(Some("232"), "233", Verdict::Fail { expected: "232".to_string() })]
+  #[case(None, "232", Verdict::Unknown)]
+  fn test_verdict(
+    #[case] expected: Option<&str>,
+    #[case] actual: &str,
+    #[case] verdict: Verdict,
+  ) {
+    assert_eq!(Verdict::new(expected, actual), verdict);
+  }
+
+  #[rstest]
+  #[case(None, "answers/y2015.toml")]
+  #[case(Some("alice"), "answers/alice/y2015.toml")]
+  fn test_path(#[case] input: Option<&str>, #[case] expected: &str) {
+    assert_eq!(AnswerStore::path(2015, input), Path::new(expected));
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("answers").join("y2015.toml");
+
+    let mut store = AnswerStore::load_from(path.clone()).unwrap();
+    assert_eq!(store.get(1), None);
+
+    let answers = DayAnswers {
+      part1: Some("232".to_string()),
+      part2: None,
+    };
+    store.set(1, answers.clone());
+    store.save().unwrap();
+
+    assert_eq!(read_to_string(&path).unwrap(), "[d01]\npart1 = \"232\"\n");
+
+    let store = AnswerStore::load_from(path).unwrap();
+    assert_eq!(store.get(1), Some(&answers));
+    assert_eq!(store.get(2), None);
+  }
+
+  #[test]
+  fn test_load_malformed() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("y2015.toml");
+    write(&path, "[d01\n").unwrap();
+
+    assert!(AnswerStore::load_from(path).is_err());
+  }
+}
@@ -0,0 +1,174 @@
+//! This module contains utilities for archiving the pages of puzzles, so that
+//! their statements can be read offline.
+//!
+//! The page of each puzzle is stored as HTML, e.g. `puzzles/y2015/d01.html`.
+//! The archive is not committed, as puzzle statements may not be shared. Only
+//! the statement of the first part is available until it is solved, after
+//! which the page can be downloaded again to include the second part.
+
+use std::{
+  error::Error,
+  fs::{create_dir_all, read_to_string, write},
+  path::{Path, PathBuf},
+};
+
+use crate::client::{html_text, parse_title, Client};
+
+/// Returns the path to the archived page of the given puzzle.
+pub fn path(year: u32, day: u32) -> PathBuf {
+  Path::new("puzzles")
+    .join(format!("y{year}"))
+    .join(format!("d{day:0>2}"))
+    .with_extension("html")
+}
+
+/// Returns the archived page of the given puzzle, if there is one.
+pub fn load(year: u32, day: u32) -> Option<String> {
+  read_to_string(path(year, day)).ok()
+}
+
+/// Downloads the page of the given puzzle into the archive, replacing any
+/// earlier copy.
+pub fn fetch(
+  client: &Client,
+  year: u32,
+  day: u32,
+) -> Result<String, Box<dyn Error>> {
+  let page = client.puzzle(year, day)?;
+  let path = path(year, day);
+
+  if let Some(parent) = path.parent() {
+    create_dir_all(parent)?;
+  }
+
+  write(path, &page)?;
+  Ok(page)
+}
+
+/// Returns the archived page of the given puzzle, downloading it first if it
+/// is not archived yet or if `refresh` is set.
+pub fn load_or_fetch(
+  year: u32,
+  day: u32,
+  refresh: bool,
+) -> Result<String, Box<dyn Error>> {
+  match load(year, day) {
+    Some(page) if !refresh => Ok(page),
+    _ => fetch(&Client::from_env()?, year, day),
+  }
+}
+
+/// Returns the title of the given puzzle from its archived page, if there is
+/// one.
+pub fn title(year: u32, day: u32) -> Option<String> {
+  parse_title(&load(year, day)?)
+}
+
+/// Returns the statement of a puzzle from its page, which is made of an
+/// `<article>` element for each part that is available. The dashes around the
+/// headings, such as `--- Part Two ---`, are removed.
+fn statement(page: &str) -> String {
+  page
+    .split("<article")
+    .skip(1)
+    .filter_map(|article| {
+      let (_, article) = article.split_once('>')?;
+      let (article, _) = article.split_once("</article>")?;
+      Some(format!("<article>{article}</article>"))
+    })
+    .collect::<String>()
+    .replace(">--- ", ">")
+    .replace(" ---</h2>", "</h2>")
+}
+
+/// Represents an example from the statement of a puzzle, along with its
+/// expected answer if it could be found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+  pub input: String,
+  pub answer: Option<String>,
+}
+
+/// Extracts the likely example for the first part of a puzzle from its page.
+///
+/// The example input is the first preformatted block of the statement, and its
+/// answer is the last emphasized code after it, which is how the statements
+/// usually conclude their walkthrough of the example.
+pub fn example(page: &str) -> Option<Example> {
+  let (_, article) = page.split_once("<article")?;
+  let (article, _) = article.split_once("</article>")?;
+
+  let (_, rest) = article.split_once("<pre><code>")?;
+  let (input, rest) = rest.split_once("</code></pre>")?;
+
+  let answer = rest
+    .rsplit_once("<code><em>")
+    .and_then(|(_, answer)| answer.split_once("</em></code>"))
+    .map(|(answer, _)| html_text(answer));
+
+  Some(Example {
+    input: html_text(input).trim_end_matches('\n').to_string(),
+    answer,
+  })
+}
+
+/// Converts the statement of a puzzle from its page into Markdown.
+pub fn to_markdown(page: &str) -> String {
+  html2md::parse_html(&statement(page))
+}
+
+/// Converts the statement of a puzzle from its page into plain text, wrapped
+/// to the given width, for reading in a terminal.
+pub fn to_text(page: &str, width: usize) -> String {
+  html2text::from_read(statement(page).as_bytes(), width)
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+  use crate::fixtures::PAGE;
+
+  #[test]
+  fn test_example() {
+    assert_eq!(
+      example(PAGE),
+      Some(Example {
+        input: [
+          "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000",
+          "8000", "9000", "", "10000",
+        ]
+        .join("\n"),
+        answer: Some("24000".to_string()),
+      })
+    );
+  }
+
+  #[rstest]
+  #[case(
+    "<article><pre><code>a &lt; b\n</code></pre><p>So <code><em>1</em></code>, \
+     or <code><em>&quot;x&quot;</em></code>.</p></article>",
+    Some(Example {
+      input: "a < b".to_string(),
+      answer: Some("\"x\"".to_string()),
+    })
+  )]
+  #[case(
+    "<article><pre><code>()())\n</code></pre><p>Somewhere.</p></article>",
+    Some(Example { input: "()())".to_string(), answer: None })
+  )]
+  #[case(
+    "<article><p><code>(())</code> results in floor \
+     <code>0</code>.</p></article><article><pre><code>()\n</code></pre></\
+     article>",
+    None
+  )]
+  #[case("<main><p>Please log in.</p></main>", None)]
+  fn test_example_inline(
+    #[case] page: &str,
+    #[case] expected: Option<Example>,
+  ) {
+    assert_eq!(example(page), expected);
+  }
+}
@@ -0,0 +1,284 @@
+//! This module contains the calendar of the Advent of Code events.
+//!
+//! The events have been held every December since 2015, with a puzzle per day
+//! from the 1st to the 25th until 2024, and to the 12th since 2025. Puzzles
+//! unlock at midnight in UTC-5. The final day of each event only has one part,
+//! as its second star is awarded for collecting all the others.
+
+use std::time::{Duration, SystemTime};
+
+use crate::answers::AnswerStore;
+
+/// The year of the first event.
+pub const FIRST_YEAR: u32 = 2015;
+
+/// The offset of the unlock time from midnight in UTC, in hours.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Returns the number of days in the given year's event.
+pub const fn days_in_year(year: u32) -> u32 {
+  if year >= 2025 {
+    12
+  } else {
+    25
+  }
+}
+
+/// Checks if the given year's event has the given day.
+pub const fn has_day(year: u32, day: u32) -> bool {
+  year >= FIRST_YEAR && day >= 1 && day <= days_in_year(year)
+}
+
+/// Formats the given time in RFC 3339, for display in error messages.
+fn format_time(time: SystemTime) -> String {
+  humantime::format_rfc3339_seconds(time).to_string()
+}
+
+/// Checks that the given year's event exists and has started at the given
+/// time, returning an error message otherwise.
+pub fn check_year(year: u32, now: SystemTime) -> Result<(), String> {
+  if year < FIRST_YEAR {
+    Err(format!("There is no event in {year}"))
+  } else if !is_unlocked(year, 1, now) {
+    Err(format!(
+      "The {year} event has not started yet, it starts at {}",
+      format_time(unlock_time(year, 1))
+    ))
+  } else {
+    Ok(())
+  }
+}
+
+/// Checks that the given year's event has the given day, and that its puzzle
+/// is unlocked at the given time, returning an error message otherwise.
+pub fn check_day(year: u32, day: u32, now: SystemTime) -> Result<(), String> {
+  if year >= FIRST_YEAR && !has_day(year, day) {
+    return Err(format!(
+      "There is no day {day} in the {year} event, which has {} days",
+      days_in_year(year)
+    ));
+  }
+
+  check_year(year, now)?;
+  if is_unlocked(year, day, now) {
+    Ok(())
+  } else {
+    Err(format!(
+      "Day {day} of the {year} event is not unlocked yet, it unlocks at {}",
+      format_time(unlock_time(year, day))
+    ))
+  }
+}
+
+/// Returns the number of parts of the given day's puzzle.
+pub const fn parts(year: u32, day: u32) -> u8 {
+  if day == days_in_year(year) {
+    1
+  } else {
+    2
+  }
+}
+
+/// Returns the number of days from the Unix epoch to the given date, using
+/// Howard Hinnant's `days_from_civil` algorithm.
+const fn days_from_epoch(year: u64, month: u64, day: u64) -> u64 {
+  let year = if month <= 2 { year - 1 } else { year };
+  let era = year / 400;
+  let year_of_era = year - era * 400;
+  let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+  let day_of_era =
+    year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+  era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the time at which the given day's puzzle unlocks.
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+  let days = days_from_epoch(u64::from(year), 12, u64::from(day));
+  let seconds = (days * 24 + UNLOCK_HOUR_UTC) * 60 * 60;
+
+  SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+/// Checks if the given day's puzzle is unlocked at the given time.
+pub fn is_unlocked(year: u32, day: u32, now: SystemTime) -> bool {
+  unlock_time(year, day) <= now
+}
+
+/// Returns the years whose event has started at the given time.
+pub fn years(now: SystemTime) -> impl Iterator<Item = u32> {
+  (FIRST_YEAR..).take_while(move |&year| is_unlocked(year, 1, now))
+}
+
+/// Returns the number of stars earned for each day of the given year, based
+/// on the answers recorded in the answers store.
+///
+/// The second star of the final day is earned along with all the others.
+pub fn stars(store: &AnswerStore, year: u32) -> Vec<u8> {
+  let days = days_in_year(year);
+
+  let mut stars = (1..=days)
+    .map(|day| {
+      store.get(day).map_or(0, |answers| {
+        let part2 = parts(year, day) > 1 && answers.part2.is_some();
+        u8::from(answers.part1.is_some()) + u8::from(part2)
+      })
+    })
+    .collect::<Vec<_>>();
+
+  let earned = stars.iter().map(|&s| u32::from(s)).sum::<u32>();
+  if earned == days * 2 - 1 {
+    stars[days as usize - 1] = 2;
+  }
+
+  stars
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+  use tempfile::tempdir;
+
+  use super::*;
+  use crate::answers::DayAnswers;
+
+  #[rstest]
+  #[case(1970, 1, 1, 0)]
+  #[case(2000, 3, 1, 11_017)]
+  #[case(2015, 12, 1, 16_770)]
+  #[case(2024, 12, 25, 20_082)]
+  #[case(2100, 3, 1, 47_541)]
+  fn test_days_from_epoch(
+    #[case] year: u64,
+    #[case] month: u64,
+    #[case] day: u64,
+    #[case] expected: u64,
+  ) {
+    assert_eq!(days_from_epoch(year, month, day), expected);
+  }
+
+  #[rstest]
+  #[case(2015, 1, 1_448_946_000)]
+  #[case(2024, 25, 1_735_102_800)]
+  #[case(2025, 12, 1_765_515_600)]
+  fn test_unlock_time(
+    #[case] year: u32,
+    #[case] day: u32,
+    #[case] expected: u64,
+  ) {
+    let unlock = SystemTime::UNIX_EPOCH + Duration::from_secs(expected);
+
+    assert_eq!(unlock_time(year, day), unlock);
+    assert!(!is_unlocked(year, day, unlock - Duration::from_secs(1)));
+    assert!(is_unlocked(year, day, unlock));
+  }
+
+  #[rstest]
+  #[case(2014, 25, 1, false, Err("There is no event in 2014".to_string()))]
+  #[case(2015, 25, 0, false, Err(
+    "There is no day 0 in the 2015 event, which has 25 days".to_string()
+  ))]
+  #[case(2024, 25, 25, true, Ok(()))]
+  #[case(2025, 12, 12, true, Ok(()))]
+  #[case(2025, 12, 13, false, Err(
+    "There is no day 13 in the 2025 event, which has 12 days".to_string()
+  ))]
+  fn test_days(
+    #[case] year: u32,
+    #[case] days: u32,
+    #[case] day: u32,
+    #[case] has: bool,
+    #[case] check: Result<(), String>,
+  ) {
+    assert_eq!(days_in_year(year), days);
+    assert_eq!(has_day(year, day), has);
+    assert_eq!(check_day(year, day, unlock_time(2025, 12)), check);
+  }
+
+  #[rstest]
+  #[case(2014, Err("There is no event in 2014".to_string()))]
+  #[case(2024, Ok(()))]
+  #[case(2025, Ok(()))]
+  #[case(2026, Err(
+    "The 2026 event has not started yet, it starts at 2026-12-01T05:00:00Z"
+      .to_string()
+  ))]
+  fn test_check_year(#[case] year: u32, #[case] check: Result<(), String>) {
+    assert_eq!(check_year(year, unlock_time(2025, 3)), check);
+  }
+
+  #[rstest]
+  #[case(2, Ok(()))]
+  #[case(3, Ok(()))]
+  #[case(4, Err(
+    "Day 4 of the 2025 event is not unlocked yet, it unlocks at \
+     2025-12-04T05:00:00Z"
+      .to_string()
+  ))]
+  #[case(12, Err(
+    "Day 12 of the 2025 event is not unlocked yet, it unlocks at \
+     2025-12-12T05:00:00Z"
+      .to_string()
+  ))]
+  fn test_check_day_locked(
+    #[case] day: u32,
+    #[case] check: Result<(), String>,
+  ) {
+    assert_eq!(check_day(2025, day, unlock_time(2025, 3)), check);
+  }
+
+  #[test]
+  fn test_years() {
+    let now = unlock_time(2017, 1);
+
+    assert_eq!(years(now).collect::<Vec<_>>(), [2015, 2016, 2017]);
+    assert_eq!(years(now - Duration::from_secs(1)).last(), Some(2016));
+  }
+
+  /// Returns the stars of 2025 given the solved parts of each day, in order.
+  fn stars_2025(solved: &[(bool, bool)]) -> Vec<u8> {
+    let dir = tempdir().unwrap();
+    let mut store =
+      AnswerStore::load_from(dir.path().join("y2025.toml")).unwrap();
+
+    for (day, &(part1, part2)) in (1..).zip(solved) {
+      store.set(
+        day,
+        DayAnswers {
+          part1: part1.then(|| "1".to_string()),
+          part2: part2.then(|| "2".to_string()),
+        },
+      );
+    }
+
+    stars(&store, 2025)
+  }
+
+  #[test]
+  fn test_stars_complete() {
+    let mut solved = vec![(true, true); 11];
+    solved.push((true, false));
+
+    assert_eq!(stars_2025(&solved), [2; 12]);
+  }
+
+  #[rstest]
+  #[case(&[], &[0; 12])]
+  #[case(
+    &[(true, true), (true, false), (false, false)],
+    &[2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+  )]
+  #[case(&[(true, true); 12], &[2; 12])]
+  fn test_stars(#[case] solved: &[(bool, bool)], #[case] expected: &[u8]) {
+    assert_eq!(stars_2025(solved), expected);
+  }
+
+  #[test]
+  fn test_stars_final_day_needs_every_star() {
+    // The second part of the final day is ignored, even if recorded
+    let mut solved = vec![(true, true); 12];
+    solved[4] = (true, false);
+
+    assert_eq!(stars_2025(&solved)[4..], [1, 2, 2, 2, 2, 2, 2, 1]);
+  }
+}
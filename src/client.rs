@@ -0,0 +1,462 @@
+//! This module contains a client for the Advent of Code website, which
+//! downloads puzzle inputs and pages, and submits answers on behalf of the
+//! user identified by a session cookie.
+//!
+//! The session cookie is read from the `AOC_SESSION` environment variable, or
+//! from the `~/.adventofcode.session` file. The base URL of the website can be
+//! overridden with the `AOC_BASE_URL` environment variable, e.g. to point the
+//! client at a local mock server.
+
+use std::{
+  env,
+  error::Error,
+  fmt,
+  fs::{create_dir_all, read_to_string, write},
+  path::{Path, PathBuf},
+  time::Duration,
+};
+
+use ureq::Agent;
+
+/// The environment variable that holds the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable that overrides the base URL of the website.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The base URL of the Advent of Code website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The user agent sent with every request, as requested by the website.
+const USER_AGENT: &str =
+  concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Represents a hint given by the website about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+  TooHigh,
+  TooLow,
+}
+
+/// Represents the outcome of submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+  Correct,
+  Incorrect {
+    hint: Option<Hint>,
+    wait: Option<Duration>,
+  },
+  TooSoon {
+    wait: Option<Duration>,
+  },
+  AlreadySolved,
+  Unknown {
+    message: String,
+  },
+}
+
+impl Outcome {
+  /// Parses the outcome from the message of the page returned after
+  /// submitting an answer.
+  fn parse(message: &str) -> Self {
+    if message.starts_with("That's the right answer") {
+      Self::Correct
+    } else if message.starts_with("That's not the right answer") {
+      let hint = if message.contains("too high") {
+        Some(Hint::TooHigh)
+      } else if message.contains("too low") {
+        Some(Hint::TooLow)
+      } else {
+        None
+      };
+
+      Self::Incorrect {
+        hint,
+        wait: parse_cooldown(message),
+      }
+    } else if message.starts_with("You gave an answer too recently") {
+      let wait = message
+        .split_once("You have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+        .and_then(|(wait, _)| humantime::parse_duration(wait).ok());
+
+      Self::TooSoon { wait }
+    } else if message
+      .starts_with("You don't seem to be solving the right level")
+    {
+      Self::AlreadySolved
+    } else {
+      Self::Unknown {
+        message: message.to_string(),
+      }
+    }
+  }
+}
+
+impl fmt::Display for Outcome {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Correct => write!(f, "correct"),
+      Self::Incorrect { hint: None, .. } => write!(f, "incorrect"),
+      Self::Incorrect {
+        hint: Some(Hint::TooHigh),
+        ..
+      } => write!(f, "incorrect (too high)"),
+      Self::Incorrect {
+        hint: Some(Hint::TooLow),
+        ..
+      } => write!(f, "incorrect (too low)"),
+      Self::TooSoon { wait: None } => write!(f, "too soon"),
+      Self::TooSoon { wait: Some(wait) } => {
+        write!(f, "too soon (wait {})", humantime::format_duration(*wait))
+      }
+      Self::AlreadySolved => write!(f, "already solved"),
+      Self::Unknown { message } => write!(f, "{message}"),
+    }
+  }
+}
+
+/// Parses the cooldown announced after a wrong answer, such as `please wait
+/// one minute before trying again`.
+fn parse_cooldown(message: &str) -> Option<Duration> {
+  let message = message.to_lowercase();
+  let (_, rest) = message.split_once("please wait ")?;
+  let (amount, unit) = rest.split_once(' ')?;
+
+  let amount = match amount {
+    "one" => 1,
+    amount => amount.parse().ok()?,
+  };
+  let seconds = if unit.starts_with("minute") {
+    60
+  } else if unit.starts_with("second") {
+    1
+  } else {
+    return None;
+  };
+
+  Some(Duration::from_secs(amount * seconds))
+}
+
+/// Represents a client for the Advent of Code website.
+pub struct Client {
+  agent: Agent,
+  base_url: String,
+  session: String,
+}
+
+impl Client {
+  /// Creates a client for the website at the given base URL, authenticated
+  /// with the given session cookie.
+  pub fn new(base_url: &str, session: &str) -> Self {
+    Self {
+      agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+      base_url: base_url.trim_end_matches('/').to_string(),
+      session: session.trim().to_string(),
+    }
+  }
+
+  /// Creates a client from the `AOC_BASE_URL` and `AOC_SESSION` environment
+  /// variables, falling back to the `~/.adventofcode.session` file for the
+  /// session cookie.
+  pub fn from_env() -> Result<Self, Box<dyn Error>> {
+    let base_url = env::var(BASE_URL_VAR)
+      .ok()
+      .filter(|url| !url.is_empty())
+      .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+    let session = match env::var(SESSION_VAR) {
+      Ok(session) if !session.is_empty() => session,
+      _ => {
+        let path =
+          session_path().ok_or("Failed to locate the home directory")?;
+        read_to_string(&path).map_err(|error| {
+          format!(
+            "Failed to read the session cookie from {SESSION_VAR} or {}: \
+             {error}",
+            path.display()
+          )
+        })?
+      }
+    };
+
+    Ok(Self::new(&base_url, &session))
+  }
+
+  /// Returns the URL of the given puzzle's page.
+  pub fn puzzle_url(&self, year: u32, day: u32) -> String {
+    format!("{}/{year}/day/{day}", self.base_url)
+  }
+
+  /// Sends a `GET` request to the given URL, returning the response body.
+  fn get(&self, url: &str) -> Result<String, Box<dyn Error>> {
+    Ok(
+      self
+        .agent
+        .get(url)
+        .set("Cookie", &format!("session={}", self.session))
+        .call()?
+        .into_string()?,
+    )
+  }
+
+  /// Downloads the input for the given puzzle.
+  pub fn input(&self, year: u32, day: u32) -> Result<String, Box<dyn Error>> {
+    self.get(&format!("{}/input", self.puzzle_url(year, day)))
+  }
+
+  /// Downloads the input for the given puzzle into the given file, creating
+  /// its directory if needed.
+  pub fn download_input(
+    &self,
+    year: u32,
+    day: u32,
+    path: &Path,
+  ) -> Result<(), Box<dyn Error>> {
+    let input = self.input(year, day)?;
+
+    if let Some(parent) = path.parent() {
+      create_dir_all(parent)?;
+    }
+
+    write(path, input)?;
+    Ok(())
+  }
+
+  /// Downloads the HTML page of the given puzzle, which only includes the
+  /// second part once the first part is solved.
+  pub fn puzzle(&self, year: u32, day: u32) -> Result<String, Box<dyn Error>> {
+    self.get(&self.puzzle_url(year, day))
+  }
+
+  /// Returns the title of the given puzzle.
+  pub fn title(&self, year: u32, day: u32) -> Result<String, Box<dyn Error>> {
+    let page = self.puzzle(year, day)?;
+    parse_title(&page).ok_or_else(|| "Failed to find the puzzle title".into())
+  }
+
+  /// Submits the answer to the given part of the given puzzle.
+  pub fn submit(
+    &self,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+  ) -> Result<Outcome, Box<dyn Error>> {
+    let page = self
+      .agent
+      .post(&format!("{}/answer", self.puzzle_url(year, day)))
+      .set("Cookie", &format!("session={}", self.session))
+      .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+      .into_string()?;
+
+    let message = element_text(&page, "article")
+      .ok_or("Failed to find the message in the response")?;
+
+    Ok(Outcome::parse(&message))
+  }
+}
+
+/// Returns the path to the file holding the session cookie, which is shared
+/// with other Advent of Code tools.
+fn session_path() -> Option<PathBuf> {
+  env::var_os("HOME").map(|home| Path::new(&home).join(".adventofcode.session"))
+}
+
+/// Parses the title of a puzzle from its page, where it appears in a heading
+/// such as `--- Day 1: Not Quite Lisp ---`.
+pub fn parse_title(page: &str) -> Option<String> {
+  let heading = element_text(page, "h2")?;
+  let (_, title) = heading.strip_prefix("--- Day ")?.split_once(": ")?;
+
+  Some(title.trim_end_matches('-').trim().to_string())
+}
+
+/// Returns the text of the first element with the given tag in an HTML page,
+/// without any nested tags and with its whitespace collapsed.
+fn element_text(page: &str, tag: &str) -> Option<String> {
+  let (_, rest) = page.split_once(&format!("<{tag}"))?;
+  let (_, rest) = rest.split_once('>')?;
+  let (inner, _) = rest.split_once(&format!("</{tag}>"))?;
+
+  Some(
+    html_text(inner)
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" "),
+  )
+}
+
+/// Returns the text of a fragment of HTML, without any tags and with the HTML
+/// entities that appear in puzzle pages replaced by the characters they
+/// represent.
+pub(crate) fn html_text(html: &str) -> String {
+  let mut text = String::new();
+  let mut in_tag = false;
+  for c in html.chars() {
+    match c {
+      '<' => in_tag = true,
+      '>' => in_tag = false,
+      c if !in_tag => text.push(c),
+      _ => {}
+    }
+  }
+
+  text
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&#39;", "'")
+    .replace("&apos;", "'")
+    .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+  use std::thread::{self, JoinHandle};
+
+  use rstest::rstest;
+  use tiny_http::{Response, Server};
+
+  use super::*;
+  use crate::fixtures::MINUTE;
+
+  /// Represents a request received by the mock server.
+  #[derive(Debug)]
+  struct Request {
+    method: String,
+    url: String,
+    cookie: Option<String>,
+    body: String,
+  }
+
+  /// Starts a mock server that answers a single request with the given status
+  /// and body, returning its base URL and a handle to the request it received.
+  fn mock(status: u16, body: &'static str) -> (String, JoinHandle<Request>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr());
+
+    let handle = thread::spawn(move || {
+      let mut request = server.recv().unwrap();
+
+      let mut received = Request {
+        method: request.method().to_string(),
+        url: request.url().to_string(),
+        cookie: request
+          .headers()
+          .iter()
+          .find(|header| header.field.equiv("Cookie"))
+          .map(|header| header.value.to_string()),
+        body: String::new(),
+      };
+      request
+        .as_reader()
+        .read_to_string(&mut received.body)
+        .unwrap();
+
+      request
+        .respond(Response::from_string(body).with_status_code(status))
+        .unwrap();
+      received
+    });
+
+    (base_url, handle)
+  }
+
+  #[test]
+  fn test_input() {
+    let (base_url, handle) = mock(200, "(()(()(\n");
+    let client = Client::new(&base_url, "secret\n");
+
+    assert_eq!(client.input(2015, 1).unwrap(), "(()(()(\n");
+
+    let request = handle.join().unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.url, "/2015/day/1/input");
+    assert_eq!(request.cookie.as_deref(), Some("session=secret"));
+  }
+
+  #[test]
+  fn test_input_not_found() {
+    let (base_url, handle) = mock(404, "Please don't repeatedly request this");
+    let client = Client::new(&base_url, "secret");
+
+    assert!(client.input(2015, 26).is_err());
+    handle.join().unwrap();
+  }
+
+  #[test]
+  fn test_title() {
+    let (base_url, handle) = mock(
+      200,
+      "<main><article class=\"day-desc\"><h2>--- Day 5: Doesn&#39;t He Have \
+       Intern-Elves For This? ---</h2><p>...</p></article></main>",
+    );
+    let client = Client::new(&base_url, "secret");
+
+    assert_eq!(
+      client.title(2015, 5).unwrap(),
+      "Doesn't He Have Intern-Elves For This?"
+    );
+    assert_eq!(handle.join().unwrap().url, "/2015/day/5");
+  }
+
+  #[rstest]
+  #[case(
+    "<article><p>That's the right answer!  You are <span \
+     class=\"day-success\">one gold star</span> closer.</p></article>",
+    Outcome::Correct
+  )]
+  #[case(
+    "<article><p>That's not the right answer; your answer is too high.  \
+     Please wait one minute before trying again.</p></article>",
+    Outcome::Incorrect {
+      hint: Some(Hint::TooHigh),
+      wait: Some(Duration::from_secs(MINUTE)),
+    }
+  )]
+  #[case(
+    "<article><p>That's not the right answer.  If you're stuck, make sure \
+     you're using the full input data.  Because you have guessed incorrectly \
+     4 times on this puzzle, please wait 5 minutes before trying \
+     again.</p></article>",
+    Outcome::Incorrect {
+      hint: None,
+      wait: Some(Duration::from_secs(5 * MINUTE)),
+    }
+  )]
+  #[case(
+    "<article><p>You gave an answer too recently; you have to wait after \
+     submitting an answer before trying again.  You have 1m 5s left to \
+     wait.</p></article>",
+    Outcome::TooSoon { wait: Some(Duration::from_secs(65)) }
+  )]
+  #[case(
+    "<article><p>You don't seem to be solving the right level.  Did you \
+     already complete it?</p></article>",
+    Outcome::AlreadySolved
+  )]
+  fn test_submit(#[case] page: &'static str, #[case] expected: Outcome) {
+    let (base_url, handle) = mock(200, page);
+    let client = Client::new(&base_url, "secret");
+
+    assert_eq!(client.submit(2015, 1, 2, "1783").unwrap(), expected);
+
+    let request = handle.join().unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.url, "/2015/day/1/answer");
+    assert_eq!(request.cookie.as_deref(), Some("session=secret"));
+    assert_eq!(request.body, "level=2&answer=1783");
+  }
+
+  #[rstest]
+  #[case("Please wait one minute before trying again.", Some(MINUTE))]
+  #[case("please wait 5 minutes before trying again.", Some(5 * MINUTE))]
+  #[case("please wait 30 seconds before trying again.", Some(30))]
+  #[case("please wait a while before trying again.", None)]
+  #[case("please wait 5 hours before trying again.", None)]
+  #[case("That's not the right answer.", None)]
+  fn test_parse_cooldown(#[case] message: &str, #[case] expected: Option<u64>) {
+    assert_eq!(parse_cooldown(message), expected.map(Duration::from_secs));
+  }
+}
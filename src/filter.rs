@@ -0,0 +1,137 @@
+//! This module contains utilities for selecting a subset of years or days.
+//!
+//! A filter is a comma-separated list of items, where each item is either:
+//! - a single number, e.g. `2019`
+//! - an inclusive range, e.g. `2019..=2022`
+//! - an exclusive range, e.g. `1..4`
+//! - an open range, e.g. `23..`, `..=5` or `..5`
+//!
+//! An empty filter selects everything.
+
+use std::{env, ops::RangeInclusive, str::FromStr};
+
+/// Represents a selection of numbers, such as years or days.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+  ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl Filter {
+  /// Checks if the given number is selected by the filter.
+  pub fn contains(&self, n: u32) -> bool {
+    self.ranges.is_empty() || self.ranges.iter().any(|range| range.contains(&n))
+  }
+
+  /// Loads a filter from the given environment variable. If the variable is
+  /// not set, the filter selects everything.
+  pub fn from_env(key: &str) -> Result<Self, String> {
+    env::var(key).map_or_else(|_| Ok(Self::default()), |value| value.parse())
+  }
+}
+
+/// Parses a bound of a range, which may be omitted in open ranges.
+fn parse_bound(bound: &str, default: u32) -> Result<u32, String> {
+  let bound = bound.trim();
+
+  if bound.is_empty() {
+    Ok(default)
+  } else {
+    bound
+      .parse()
+      .map_err(|error| format!("Invalid number '{bound}': {error}"))
+  }
+}
+
+/// Returns the error for a range whose start is after its end.
+fn reversed_range_error(range: &str) -> String {
+  format!("Invalid range '{range}': its start is after its end")
+}
+
+impl FromStr for Filter {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let ranges = s
+      .split(',')
+      .map(str::trim)
+      .filter(|item| !item.is_empty())
+      .map(|item| {
+        if let Some((start, end)) = item.split_once("..=") {
+          let start = parse_bound(start, 0)?;
+          let end = parse_bound(end, u32::MAX)?;
+          if start > end {
+            return Err(reversed_range_error(item));
+          }
+
+          Ok(start..=end)
+        } else if let Some((start, end)) = item.split_once("..") {
+          let start = parse_bound(start, 0)?;
+          if end.trim().is_empty() {
+            return Ok(start..=u32::MAX);
+          }
+
+          let end = parse_bound(end, 0)?;
+          if start > end {
+            return Err(reversed_range_error(item));
+          }
+
+          // An exclusive range that ends at zero selects nothing
+          Ok(
+            end
+              .checked_sub(1)
+              .map_or(RangeInclusive::new(1, 0), |end| start..=end),
+          )
+        } else {
+          let n = parse_bound(item, 0)?;
+          Ok(n..=n)
+        }
+      })
+      .collect::<Result<_, String>>()?;
+
+    Ok(Self { ranges })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  #[rstest]
+  #[case("", &[1, 2015, u32::MAX], &[])]
+  #[case("2019", &[2019], &[2018, 2020])]
+  #[case("2019..2022", &[2019, 2021], &[2018, 2022])]
+  #[case("2019..=2022", &[2019, 2022], &[2018, 2023])]
+  #[case("..5", &[0, 4], &[5])]
+  #[case("..=5", &[0, 5], &[6])]
+  #[case("23..", &[23, u32::MAX], &[22])]
+  #[case("1, 3..5, 10..=11", &[1, 3, 4, 10, 11], &[2, 5, 9, 12])]
+  #[case("..0", &[], &[0, 1])]
+  #[case("3..3", &[], &[2, 3])]
+  fn test_contains(
+    #[case] filter: &str,
+    #[case] selected: &[u32],
+    #[case] unselected: &[u32],
+  ) {
+    let filter = filter.parse::<Filter>().unwrap();
+
+    for n in selected {
+      assert!(filter.contains(*n), "{n} should be selected");
+    }
+    for n in unselected {
+      assert!(!filter.contains(*n), "{n} should not be selected");
+    }
+  }
+
+  #[rstest]
+  #[case("5..3")]
+  #[case("5..=3")]
+  #[case("a")]
+  #[case("1..b")]
+  #[case("-1")]
+  #[case("1...3")]
+  fn test_invalid(#[case] filter: &str) {
+    assert!(filter.parse::<Filter>().is_err());
+  }
+}
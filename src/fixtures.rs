@@ -0,0 +1,16 @@
+//! This module contains the fixtures shared by the tests of several modules.
+
+/// The number of seconds in a minute.
+pub const MINUTE: u64 = 60;
+
+/// The README at the root of the repository.
+pub const README: &str = include_str!("scripts/fixtures/README.md");
+
+/// The README of a year with benchmarks of all magnitudes.
+pub const YEAR_README: &str = include_str!("scripts/fixtures/y2015.md");
+
+/// The README of a year that was just scaffolded.
+pub const EMPTY_YEAR_README: &str = include_str!("scripts/fixtures/y2025.md");
+
+/// The page of a puzzle whose two parts are solved.
+pub const PAGE: &str = include_str!("scripts/fixtures/puzzle.html");
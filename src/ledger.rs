@@ -0,0 +1,468 @@
+//! This module contains utilities for keeping a ledger of the answers that
+//! were submitted for each puzzle, so that answers that are known to be wrong
+//! are never submitted twice.
+//!
+//! The ledger for each puzzle is stored in a TOML file, e.g.
+//! `ledger/y2015/d01.toml`:
+//!
+//! ```toml
+//! cooldown_until = "2015-12-01T05:01:00Z"
+//!
+//! [[part1]]
+//! answer = "250"
+//! feedback = "too_high"
+//! submitted_at = "2015-12-01T05:00:00Z"
+//! ```
+
+use std::{
+  error::Error,
+  fmt,
+  fs::{create_dir_all, read_to_string, write},
+  path::{Path, PathBuf},
+  time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+  client::{Hint, Outcome},
+  util::serde::{deserialize_option, serialize_option, Format},
+};
+
+/// Represents the format of timestamps in RFC 3339, to the second.
+struct Rfc3339;
+
+impl Format for Rfc3339 {
+  type Value = SystemTime;
+
+  fn serialize<S: Serializer>(
+    timestamp: &SystemTime,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer
+      .serialize_str(&humantime::format_rfc3339_seconds(*timestamp).to_string())
+  }
+
+  fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<SystemTime, D::Error> {
+    humantime::parse_rfc3339(&String::deserialize(deserializer)?)
+      .map_err(serde::de::Error::custom)
+  }
+}
+
+/// Represents the feedback given by the website on a submitted answer.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Feedback {
+  Correct,
+  TooHigh,
+  TooLow,
+  Wrong,
+}
+
+impl fmt::Display for Feedback {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let feedback = match self {
+      Self::Correct => "correct",
+      Self::TooHigh => "too high",
+      Self::TooLow => "too low",
+      Self::Wrong => "wrong",
+    };
+
+    write!(f, "{feedback}")
+  }
+}
+
+/// Represents an answer that was submitted, along with the feedback on it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+  pub answer: String,
+  pub feedback: Feedback,
+  #[serde(
+    serialize_with = "Rfc3339::serialize",
+    deserialize_with = "Rfc3339::deserialize"
+  )]
+  pub submitted_at: SystemTime,
+}
+
+/// Represents the reason for refusing to submit an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+  AlreadyCorrect { answer: String },
+  KnownWrong { feedback: Feedback },
+  AboveBound { bound: i128 },
+  BelowBound { bound: i128 },
+  CoolingDown { remaining: Duration },
+}
+
+impl fmt::Display for Refusal {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::AlreadyCorrect { answer } => {
+        write!(f, "the part was already solved with {answer}")
+      }
+      Self::KnownWrong { feedback } => {
+        write!(f, "the answer was already submitted and is {feedback}")
+      }
+      Self::AboveBound { bound } => {
+        write!(f, "the answer must be lower than {bound}")
+      }
+      Self::BelowBound { bound } => {
+        write!(f, "the answer must be higher than {bound}")
+      }
+      Self::CoolingDown { remaining } => write!(
+        f,
+        "the website asked to wait another {}",
+        humantime::format_duration(Duration::from_secs(remaining.as_secs()))
+      ),
+    }
+  }
+}
+
+/// Represents the ledger of the answers submitted for a puzzle.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Ledger {
+  #[serde(skip)]
+  path: PathBuf,
+  #[serde(
+    default,
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_option::<Rfc3339, _>",
+    deserialize_with = "deserialize_option::<Rfc3339, _>"
+  )]
+  cooldown_until: Option<SystemTime>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  part1: Vec<Submission>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  part2: Vec<Submission>,
+}
+
+impl Ledger {
+  /// Returns the path to the ledger file for the given puzzle.
+  pub fn path(year: u32, day: u32) -> PathBuf {
+    Path::new("ledger")
+      .join(format!("y{year}"))
+      .join(format!("d{day:0>2}"))
+      .with_extension("toml")
+  }
+
+  /// Loads the ledger for the given puzzle. If there is no ledger file for the
+  /// puzzle, the ledger is empty.
+  pub fn load(year: u32, day: u32) -> Result<Self, Box<dyn Error>> {
+    let path = Self::path(year, day);
+
+    let mut ledger = if path.exists() {
+      toml::from_str::<Self>(&read_to_string(&path)?)?
+    } else {
+      Self::default()
+    };
+
+    ledger.path = path;
+    Ok(ledger)
+  }
+
+  /// Writes the ledger back to the ledger file.
+  pub fn save(&self) -> Result<(), Box<dyn Error>> {
+    if let Some(parent) = self.path.parent() {
+      create_dir_all(parent)?;
+    }
+
+    write(&self.path, toml::to_string(self)?)?;
+    Ok(())
+  }
+
+  /// Returns the answers submitted for the given part, in submission order.
+  pub fn submissions(&self, part: u8) -> &[Submission] {
+    if part == 1 {
+      &self.part1
+    } else {
+      &self.part2
+    }
+  }
+
+  /// Checks if the given answer to the given part may be submitted at the
+  /// given time, based on the earlier submissions and the rate limit.
+  ///
+  /// Integer answers must also fall within the bounds learned from the answers
+  /// that were too high or too low.
+  pub fn check(
+    &self,
+    part: u8,
+    answer: &str,
+    now: SystemTime,
+  ) -> Result<(), Refusal> {
+    let submissions = self.submissions(part);
+
+    if let Some(correct) = submissions
+      .iter()
+      .find(|submission| submission.feedback == Feedback::Correct)
+    {
+      return Err(Refusal::AlreadyCorrect {
+        answer: correct.answer.clone(),
+      });
+    }
+
+    if let Some(submission) = submissions
+      .iter()
+      .find(|submission| submission.answer == answer)
+    {
+      return Err(Refusal::KnownWrong {
+        feedback: submission.feedback,
+      });
+    }
+
+    if let Ok(answer) = answer.parse::<i128>() {
+      let bound = |feedback| {
+        submissions
+          .iter()
+          .filter(move |submission| submission.feedback == feedback)
+          .filter_map(|submission| submission.answer.parse::<i128>().ok())
+      };
+
+      if let Some(bound) = bound(Feedback::TooHigh).min() {
+        if answer >= bound {
+          return Err(Refusal::AboveBound { bound });
+        }
+      }
+
+      if let Some(bound) = bound(Feedback::TooLow).max() {
+        if answer <= bound {
+          return Err(Refusal::BelowBound { bound });
+        }
+      }
+    }
+
+    if let Some(remaining) = self
+      .cooldown_until
+      .and_then(|until| until.duration_since(now).ok())
+      .filter(|remaining| !remaining.is_zero())
+    {
+      return Err(Refusal::CoolingDown { remaining });
+    }
+
+    Ok(())
+  }
+
+  /// Records the outcome of submitting the given answer to the given part at
+  /// the given time, along with any cooldown the website asked for.
+  pub fn record(
+    &mut self,
+    part: u8,
+    answer: &str,
+    outcome: &Outcome,
+    now: SystemTime,
+  ) {
+    let (feedback, wait) = match *outcome {
+      Outcome::Correct => (Some(Feedback::Correct), None),
+      Outcome::Incorrect { hint, wait } => (
+        Some(match hint {
+          Some(Hint::TooHigh) => Feedback::TooHigh,
+          Some(Hint::TooLow) => Feedback::TooLow,
+          None => Feedback::Wrong,
+        }),
+        wait,
+      ),
+      Outcome::TooSoon { wait } => (None, wait),
+      Outcome::AlreadySolved | Outcome::Unknown { .. } => (None, None),
+    };
+
+    self.cooldown_until = wait.map(|wait| now + wait);
+
+    if let Some(feedback) = feedback {
+      let submission = Submission {
+        answer: answer.to_string(),
+        feedback,
+        submitted_at: now,
+      };
+
+      if part == 1 {
+        self.part1.push(submission);
+      } else {
+        self.part2.push(submission);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+  use crate::{calendar, fixtures::MINUTE};
+
+  /// Returns the time at which the first puzzle unlocked, at which the answers
+  /// are submitted in the tests.
+  fn unlock() -> SystemTime {
+    calendar::unlock_time(2015, 1)
+  }
+
+  /// Returns a ledger with the given submissions to the first part.
+  fn ledger(submissions: &[(&str, Feedback)]) -> Ledger {
+    Ledger {
+      part1: submissions
+        .iter()
+        .map(|&(answer, feedback)| Submission {
+          answer: answer.to_string(),
+          feedback,
+          submitted_at: unlock(),
+        })
+        .collect(),
+      ..Ledger::default()
+    }
+  }
+
+  #[rstest]
+  #[case(&[], "232", Ok(()))]
+  #[case(
+    &[("232", Feedback::Correct)],
+    "233",
+    Err(Refusal::AlreadyCorrect { answer: "232".to_string() })
+  )]
+  #[case(
+    &[("abc", Feedback::Wrong)],
+    "abc",
+    Err(Refusal::KnownWrong { feedback: Feedback::Wrong })
+  )]
+  #[case(
+    &[("250", Feedback::TooHigh)],
+    "250",
+    Err(Refusal::KnownWrong { feedback: Feedback::TooHigh })
+  )]
+  #[case(
+    &[("250", Feedback::TooHigh), ("300", Feedback::TooHigh)],
+    "260",
+    Err(Refusal::AboveBound { bound: 250 })
+  )]
+  #[case(
+    &[("100", Feedback::TooLow), ("50", Feedback::TooLow)],
+    "75",
+    Err(Refusal::BelowBound { bound: 100 })
+  )]
+  #[case(
+    &[("250", Feedback::TooHigh), ("100", Feedback::TooLow)],
+    "232",
+    Ok(())
+  )]
+  #[case(&[("250", Feedback::TooHigh)], "abc", Ok(()))]
+  fn test_check(
+    #[case] submissions: &[(&str, Feedback)],
+    #[case] answer: &str,
+    #[case] expected: Result<(), Refusal>,
+  ) {
+    assert_eq!(ledger(submissions).check(1, answer, unlock()), expected);
+    assert_eq!(ledger(submissions).check(2, answer, unlock()), Ok(()));
+  }
+
+  #[rstest]
+  #[case(30, Err(Refusal::CoolingDown { remaining: Duration::from_secs(30) }))]
+  #[case(MINUTE, Ok(()))]
+  #[case(2 * MINUTE, Ok(()))]
+  fn test_check_cooldown(
+    #[case] elapsed: u64,
+    #[case] expected: Result<(), Refusal>,
+  ) {
+    let mut ledger = Ledger::default();
+    let outcome = Outcome::Incorrect {
+      hint: None,
+      wait: Some(Duration::from_secs(MINUTE)),
+    };
+    ledger.record(1, "250", &outcome, unlock());
+
+    let now = unlock() + Duration::from_secs(elapsed);
+    assert_eq!(ledger.check(1, "232", now), expected);
+  }
+
+  #[rstest]
+  #[case(Outcome::Correct, Some(Feedback::Correct), None)]
+  #[case(
+    Outcome::Incorrect { hint: Some(Hint::TooHigh), wait: None },
+    Some(Feedback::TooHigh),
+    None
+  )]
+  #[case(
+    Outcome::Incorrect { hint: Some(Hint::TooLow), wait: None },
+    Some(Feedback::TooLow),
+    None
+  )]
+  #[case(
+    Outcome::Incorrect { hint: None, wait: Some(Duration::from_secs(MINUTE)) },
+    Some(Feedback::Wrong),
+    Some(MINUTE)
+  )]
+  #[case(
+    Outcome::TooSoon { wait: Some(Duration::from_secs(65)) },
+    None,
+    Some(65)
+  )]
+  #[case(Outcome::AlreadySolved, None, None)]
+  #[case(Outcome::Unknown { message: String::new() }, None, None)]
+  fn test_record(
+    #[case] outcome: Outcome,
+    #[case] feedback: Option<Feedback>,
+    #[case] wait: Option<u64>,
+  ) {
+    let mut ledger = Ledger::default();
+    ledger.record(2, "232", &outcome, unlock());
+
+    let expected = feedback.map(|feedback| Submission {
+      answer: "232".to_string(),
+      feedback,
+      submitted_at: unlock(),
+    });
+    assert_eq!(ledger.submissions(1), []);
+    assert_eq!(ledger.submissions(2), expected.as_slice());
+    assert_eq!(
+      ledger.cooldown_until,
+      wait.map(|wait| unlock() + Duration::from_secs(wait))
+    );
+  }
+
+  #[test]
+  fn test_serialize() {
+    let mut ledger = Ledger::default();
+    let outcome = Outcome::Incorrect {
+      hint: Some(Hint::TooHigh),
+      wait: Some(Duration::from_secs(MINUTE)),
+    };
+    ledger.record(1, "250", &outcome, unlock());
+
+    let content = toml::to_string(&ledger).unwrap();
+    assert_eq!(
+      content,
+      "cooldown_until = \"2015-12-01T05:01:00Z\"\n\n[[part1]]\nanswer = \
+       \"250\"\nfeedback = \"too_high\"\nsubmitted_at = \
+       \"2015-12-01T05:00:00Z\"\n"
+    );
+
+    let loaded = toml::from_str::<Ledger>(&content).unwrap();
+    assert_eq!(loaded.cooldown_until, ledger.cooldown_until);
+    assert_eq!(loaded.part1, ledger.part1);
+  }
+
+  #[rstest]
+  #[case(
+    Refusal::AlreadyCorrect { answer: "232".to_string() },
+    "the part was already solved with 232"
+  )]
+  #[case(
+    Refusal::KnownWrong { feedback: Feedback::TooLow },
+    "the answer was already submitted and is too low"
+  )]
+  #[case(
+    Refusal::AboveBound { bound: 250 },
+    "the answer must be lower than 250"
+  )]
+  #[case(
+    Refusal::BelowBound { bound: 100 },
+    "the answer must be higher than 100"
+  )]
+  #[case(
+    Refusal::CoolingDown { remaining: Duration::from_millis(65_500) },
+    "the website asked to wait another 1m 5s"
+  )]
+  fn test_refusal_display(#[case] refusal: Refusal, #[case] expected: &str) {
+    assert_eq!(refusal.to_string(), expected);
+  }
+}
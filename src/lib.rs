@@ -3,16 +3,19 @@
 #![feature(iter_array_chunks)]
 #![feature(let_chains)]
 
+pub mod answers;
+pub mod archive;
+pub mod calendar;
+pub mod client;
+#[cfg(test)]
+mod fixtures;
+pub mod filter;
+pub mod ledger;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
 pub mod scripts;
 pub mod util;
-pub mod y2015;
-pub mod y2016;
-pub mod y2017;
-pub mod y2018;
-pub mod y2019;
-pub mod y2020;
-pub mod y2021;
-pub mod y2022;
-pub mod y2023;
-pub mod y2024;
-pub mod y2025;
+
+// The modules of each year are discovered by the build script
+include!(concat!(env!("OUT_DIR"), "/modules.rs"));
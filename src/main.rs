@@ -1,179 +1,847 @@
 #![allow(clippy::print_stdout)]
 use std::{
-  cmp::Ordering,
+  collections::{btree_map::Entry, BTreeMap},
+  env::current_exe,
+  error::Error,
   fs::read_to_string,
+  io::{self, ErrorKind, Read},
+  num::NonZeroUsize,
   path::{Path, PathBuf},
+  process::{Command, ExitCode, Stdio},
+  sync::Mutex,
+  thread,
+  time::{Duration, SystemTime},
 };
 
-use aoc::util::parse::ParseOps;
-use clap::{Parser, ValueEnum};
-use lazy_static::lazy_static;
-use prettytable::{
-  cell,
-  format::{FormatBuilder, LinePosition, LineSeparator},
-  row, table,
+use aoc::{
+  answers::{AnswerStore, Verdict},
+  archive, calendar,
+  client::{parse_title, Client, Outcome},
+  filter::Filter,
+  ledger::Ledger,
+  registry::{self, input_path, Answers, Input, Solver},
+  report::{self, Checks, Solution, Status},
+  scripts::scaffold::Plan,
+  util::thread::spawn_parallel_iterator_with_threads,
 };
-use serde::Serialize;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use wait_timeout::ChildExt;
 
-#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
 enum OutputFormat {
   #[default]
   Table,
   Json,
+  Csv,
+  /// A markdown table, e.g. to paste into a pull request
+  Markdown,
+  /// A JSON object per line, printed as soon as each puzzle is solved
+  Ndjson,
+  /// `JUnit` XML with a test case per part, which requires `--check`
+  Junit,
 }
 
+/// Represents an action to perform instead of solving puzzles.
+#[derive(Subcommand, Debug)]
+enum Action {
+  /// Download the input for a puzzle into the input directory
+  Download { year: u32, day: u32 },
+  /// Print the title of a puzzle, archiving its page if needed
+  Title { year: u32, day: u32 },
+  /// Print the statement of a puzzle from the archive, archiving its page if
+  /// needed
+  Read {
+    year: u32,
+    day: u32,
+    /// Print the statement as Markdown instead of plain text
+    #[arg(long)]
+    markdown: bool,
+    /// Wrap the plain text to the given width
+    #[arg(long, default_value = "80")]
+    width: usize,
+    /// Download the page again, e.g. to include the second part once the
+    /// first part is solved
+    #[arg(long)]
+    refresh: bool,
+  },
+  /// Solve a part of a puzzle and submit the answer
+  Submit {
+    year: u32,
+    day: u32,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+  },
+  /// Scaffold the boilerplate for a year's event, or for a day's puzzle along
+  /// with its year's event if needed
+  Scaffold {
+    year: u32,
+    day: Option<u32>,
+    /// Print the planned file changes without applying them
+    #[arg(long)]
+    dry_run: bool,
+    /// Also download the input for the day's puzzle
+    #[arg(long)]
+    download: bool,
+    /// Use the given title instead of the one from the puzzle page
+    #[arg(long)]
+    title: Option<String>,
+  },
+  /// Show the stars earned in each year's event, based on the recorded
+  /// answers
+  Calendar,
+  /// Back out the scaffolding of a day's puzzle, deleting its module along
+  /// with its README entry
+  Unscaffold {
+    year: u32,
+    day: u32,
+    /// Print the planned file changes without applying them
+    #[arg(long)]
+    dry_run: bool,
+  },
+}
+
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
+  #[command(subcommand)]
+  action: Option<Action>,
+
+  /// Only solve the puzzles of the given years (e.g. `2019..=2022`)
   #[arg(short, long)]
-  year: Option<u32>,
+  year: Option<Filter>,
 
+  /// Only solve the puzzles of the given days (e.g. `1,3,5` or `23..`)
   #[arg(short, long)]
-  day: Option<u32>,
+  day: Option<Filter>,
 
   #[arg(short, long, default_value = "table")]
   format: OutputFormat,
+
+  /// Measure the time taken to parse the input and to solve each part
+  #[arg(short, long)]
+  time: bool,
+
+  /// Compare the answers against the known answers in the answers store
+  #[arg(short, long, conflicts_with_all = ["record", "input"])]
+  check: bool,
+
+  /// Write the answers into the answers store
+  #[arg(short, long, conflicts_with = "input")]
+  record: bool,
+
+  /// Only solve the given part of each puzzle
+  #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+  part: Option<u8>,
+
+  /// Run each puzzle in a child process that is killed if it takes longer
+  /// than the given duration (e.g. `30s`, `2m`)
+  #[arg(long, value_parser = humantime::parse_duration)]
+  timeout: Option<Duration>,
+
+  /// Solve the puzzles on the given number of worker threads
+  #[arg(short, long, default_value = "1")]
+  jobs: NonZeroUsize,
+
+  /// Solve the puzzles whose solutions spawn threads of their own one at a
+  /// time, after the others, so that they do not compete for cores
+  #[arg(long)]
+  isolate_multithreaded: bool,
+
+  /// Read the input from the given file instead of the input directory, or
+  /// from `stdin` if it is `-`. Only a single puzzle may be selected
+  #[arg(short, long)]
+  input: Option<PathBuf>,
+
+  /// Read the inputs from the given directory [default: `$AOC_INPUT_DIR` or
+  /// `input`]
+  #[arg(long, global = true)]
+  input_dir: Option<PathBuf>,
+}
+
+/// Checks if the given input path refers to `stdin`.
+fn is_stdin(path: &Path) -> bool {
+  path == Path::new("-")
+}
+
+/// Reads the puzzle input from the given path, or from `stdin` if the path is
+/// `-`.
+fn read_input(path: &Path) -> io::Result<String> {
+  if is_stdin(path) {
+    io::read_to_string(io::stdin())
+  } else {
+    read_to_string(path)
+  }
+}
+
+/// Reads the puzzle input and runs the solver on it, capturing any failure
+/// in the status of the solution instead of aborting.
+///
+/// Only the given part is solved, if any. The durations of each function are
+/// only included if `time` is set.
+fn solve(
+  solver: &Solver,
+  input: &Input,
+  part: Option<u8>,
+  time: bool,
+) -> Solution {
+  let mut solution = Solution {
+    year: solver.year,
+    day: solver.day,
+    input: input.name.clone(),
+    status: Status::Ok,
+    part1: None,
+    part2: None,
+    durations: None,
+    check: None,
+    error: None,
+  };
+
+  match read_input(&input.path) {
+    Ok(data) => match solver.solve(&data, part) {
+      Ok(Answers {
+        part1,
+        part2,
+        durations,
+      }) => {
+        // A part that panicked does not discard the answer to the other one
+        if let Some(error) = [&part1, &part2]
+          .into_iter()
+          .flatten()
+          .find_map(|answer| answer.as_ref().err())
+        {
+          solution.status = Status::Panicked;
+          solution.error = Some(error.clone());
+        }
+
+        solution.part1 = part1.and_then(Result::ok);
+        solution.part2 = part2.and_then(Result::ok);
+        solution.durations = time.then_some(durations);
+      }
+      Err(error) => {
+        solution.status = Status::Panicked;
+        solution.error = Some(error);
+      }
+    },
+    Err(error) if error.kind() == ErrorKind::NotFound => {
+      solution.status = Status::InputMissing;
+    }
+    Err(error) => {
+      solution.status = Status::Unreadable;
+      solution.error = Some(error.to_string());
+    }
+  }
+
+  solution
+}
+
+/// Returns the command that solves the puzzle in a child process running this
+/// same executable, with its output piped back as JSON.
+fn child_command(
+  solver: &Solver,
+  input: &Input,
+  part: Option<u8>,
+  time: bool,
+) -> io::Result<Command> {
+  let mut command = Command::new(current_exe()?);
+  command
+    .args(["--year", &solver.year.to_string()])
+    .args(["--day", &solver.day.to_string()])
+    .args(["--format", "json"])
+    .arg("--input")
+    .arg(&input.path)
+    .stdout(Stdio::piped());
+
+  // Let the child read the input directly from our own `stdin`
+  if is_stdin(&input.path) {
+    command.stdin(Stdio::inherit());
+  } else {
+    command.stdin(Stdio::null());
+  }
+
+  if let Some(part) = part {
+    command.args(["--part", &part.to_string()]);
+  }
+  if time {
+    command.arg("--time");
+  }
+
+  Ok(command)
 }
 
-struct Solver {
+/// Solves the puzzle in a child process running this same executable, so
+/// that it can be killed if it does not finish within the timeout.
+///
+/// Failing to run or wait for the child process is reported in the status of
+/// the solution instead of aborting.
+fn solve_in_child_process(
+  solver: &Solver,
+  input: &Input,
+  part: Option<u8>,
+  time: bool,
+  timeout: Duration,
+) -> Solution {
+  let failure = |status, error| Solution {
+    year: solver.year,
+    day: solver.day,
+    input: input.name.clone(),
+    status,
+    part1: None,
+    part2: None,
+    durations: None,
+    check: None,
+    error: Some(error),
+  };
+  let process_failure = |error: io::Error| {
+    failure(
+      Status::ProcessFailed,
+      format!("child process failed: {error}"),
+    )
+  };
+
+  let mut child = match child_command(solver, input, part, time)
+    .and_then(|mut command| command.spawn())
+  {
+    Ok(child) => child,
+    Err(error) => return process_failure(error),
+  };
+
+  // Read the output on a separate thread, so that the child never blocks on
+  // a full pipe while the watchdog is waiting on it
+  let mut stdout = child.stdout.take().unwrap();
+  let reader = thread::spawn(move || {
+    let mut output = String::new();
+    stdout.read_to_string(&mut output).map(|_| output)
+  });
+
+  let exit_status = match child.wait_timeout(timeout) {
+    Ok(exit_status) => exit_status,
+    Err(error) => {
+      let _ = child.kill();
+      return process_failure(error);
+    }
+  };
+
+  let Some(exit_status) = exit_status else {
+    // The child may exit just before being killed, in which case killing it
+    // fails but waiting for it still reaps it
+    let _ = child.kill();
+    if let Err(error) = child.wait() {
+      return process_failure(error);
+    }
+
+    return failure(
+      Status::TimedOut,
+      format!("exceeded {}", humantime::format_duration(timeout)),
+    );
+  };
+
+  reader
+    .join()
+    .ok()
+    .and_then(Result::ok)
+    .and_then(|output| serde_json::from_str::<Vec<Solution>>(&output).ok())
+    .and_then(|mut solutions| solutions.pop())
+    .map_or_else(
+      || {
+        failure(
+          Status::Panicked,
+          format!("child process exited with {exit_status}"),
+        )
+      },
+      |solution| Solution {
+        input: input.name.clone(),
+        ..solution
+      },
+    )
+}
+
+/// Loads the answers store for the given year and input.
+fn load_store(
   year: u32,
-  day: u32,
-  path: PathBuf,
-  wrapper: fn(String) -> (String, String),
+  input: Option<&str>,
+) -> Result<AnswerStore, Box<dyn Error>> {
+  AnswerStore::load(year, input).map_err(|error| {
+    format!(
+      "Failed to load {}: {error}",
+      AnswerStore::path(year, input).display()
+    )
+    .into()
+  })
 }
 
-macro_rules! solver {
-  ($year:tt, $day:tt) => {{
-    let year = stringify!($year);
-    let day = stringify!($day);
-    let path = Path::new("input")
-      .join(year)
-      .join(day)
-      .with_extension("txt");
+/// Represents the answers stores, which the solutions are checked against
+/// and/or recorded into as soon as they are solved.
+struct Verifier {
+  check: bool,
+  record: bool,
+  stores: Mutex<BTreeMap<(u32, Option<String>), AnswerStore>>,
+}
 
-    let wrapper = |data: String| {
-      use aoc::$year::$day::*;
+impl Verifier {
+  /// Loads the answers stores of the given attempts upfront, so that a store
+  /// that fails to load is reported before any puzzle is solved.
+  fn new(
+    check: bool,
+    record: bool,
+    attempts: &[(&Solver, Input)],
+  ) -> Result<Self, Box<dyn Error>> {
+    let mut stores = BTreeMap::new();
 
-      let input = parse(&data);
-      let part1 = p1(&input);
-      let part2 = p2(&input);
+    if check || record {
+      for (solver, input) in attempts {
+        if let Entry::Vacant(entry) =
+          stores.entry((solver.year, input.name.clone()))
+        {
+          entry.insert(load_store(solver.year, input.name.as_deref())?);
+        }
+      }
+    }
 
-      (part1.to_string(), part2.to_string())
-    };
+    Ok(Self {
+      check,
+      record,
+      stores: Mutex::new(stores),
+    })
+  }
 
-    Solver {
-      year: year.unsigned(),
-      day: day.unsigned(),
-      path,
-      wrapper,
+  /// Compares the answers of a solved puzzle against the answers store, and/or
+  /// records them into it.
+  fn verify(&self, solution: &mut Solution) {
+    let is_solved = solution.part1.is_some() || solution.part2.is_some();
+    if !is_solved || !(self.check || self.record) {
+      return;
     }
-  }};
+
+    let mut stores = self.stores.lock().unwrap();
+    let store = stores
+      .get_mut(&(solution.year, solution.input.clone()))
+      .expect("the answers store should be loaded upfront");
+
+    let mut answers = store.get(solution.day).cloned().unwrap_or_default();
+    let part1 = solution.part1.as_ref().map(ToString::to_string);
+    let part2 = solution.part2.as_ref().map(ToString::to_string);
+
+    if self.check {
+      solution.check = Some(Checks {
+        part1: part1
+          .as_deref()
+          .map(|actual| Verdict::new(answers.part1.as_deref(), actual)),
+        part2: part2
+          .as_deref()
+          .map(|actual| Verdict::new(answers.part2.as_deref(), actual)),
+      });
+    }
+
+    if self.record {
+      // Only overwrite the answers for the parts that were solved
+      answers.part1 = part1.or(answers.part1);
+      answers.part2 = part2.or(answers.part2);
+      store.set(solution.day, answers);
+    }
+
+    drop(stores);
+  }
+
+  /// Writes the recorded answers back to their answers stores.
+  fn save(&self) -> Result<(), Box<dyn Error>> {
+    if !self.record {
+      return Ok(());
+    }
+
+    for store in self.stores.lock().unwrap().values() {
+      store.save().map_err(|error| {
+        format!("Failed to save {}: {error}", store.file().display())
+      })?;
+      eprintln!("Recorded answers in {}", store.file().display());
+    }
+
+    Ok(())
+  }
 }
 
-include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
+/// Solves each of the given attempts on one of the given number of worker
+/// threads. If `isolate_multithreaded` is set, the puzzles whose solutions
+/// spawn threads of their own are solved one at a time, after the others.
+fn solve_all<F>(
+  attempts: Vec<(&Solver, Input)>,
+  jobs: NonZeroUsize,
+  isolate_multithreaded: bool,
+  solve: F,
+) -> Vec<Solution>
+where
+  F: Fn(&(&Solver, Input)) -> Solution + Copy + Send + Sync,
+{
+  let (isolated, shared): (Vec<_>, Vec<_>) = attempts
+    .into_iter()
+    .partition(|(solver, _)| isolate_multithreaded && solver.multithreaded);
+
+  // Spread the puzzles over the worker threads, stealing work from each other
+  let mut solutions =
+    spawn_parallel_iterator_with_threads(jobs.get(), &shared, |iter| {
+      iter.map(solve).collect::<Vec<_>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
 
-#[derive(Serialize, PartialEq, Eq)]
-struct Solution {
+  solutions.extend(isolated.iter().map(solve));
+  solutions
+}
+
+/// Returns the path to the single input file for the given puzzle, which is
+/// the one the client downloads and submits answers for.
+///
+/// The client only has the session of one account, so it refuses a puzzle with
+/// named inputs rather than silently using the unnamed one.
+fn single_input_path(
+  input_dir: &Path,
   year: u32,
   day: u32,
-  part1: String,
-  part2: String,
+) -> Result<PathBuf, String> {
+  let dir = registry::named_inputs_dir(input_dir, year, day);
+  if dir.is_dir() {
+    return Err(format!(
+      "{year} day {day} has named inputs in {}, which the client cannot \
+       download or submit answers for",
+      dir.display()
+    ));
+  }
+
+  Ok(input_path(input_dir, year, day))
 }
 
-impl PartialOrd for Solution {
-  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    Some(self.cmp(other))
+/// Performs the given action through the Advent of Code client.
+fn perform(
+  action: &Action,
+  input_dir: &Path,
+) -> Result<ExitCode, Box<dyn Error>> {
+  match *action {
+    Action::Download { year, day } => {
+      let path = single_input_path(input_dir, year, day)?;
+      Client::from_env()?.download_input(year, day, &path)?;
+      eprintln!("Downloaded input to {}", path.display());
+    }
+    Action::Title { year, day } => {
+      let page = archive::load_or_fetch(year, day, false)?;
+      println!(
+        "{}",
+        parse_title(&page).ok_or("Failed to find the puzzle title")?
+      );
+    }
+    Action::Read {
+      year,
+      day,
+      markdown,
+      width,
+      refresh,
+    } => {
+      let page = archive::load_or_fetch(year, day, refresh)?;
+      if markdown {
+        println!("{}", archive::to_markdown(&page));
+      } else {
+        println!("{}", archive::to_text(&page, width));
+      }
+    }
+    Action::Submit { year, day, part } => {
+      return submit(&Client::from_env()?, input_dir, year, day, part);
+    }
+    Action::Scaffold {
+      year,
+      day,
+      dry_run,
+      download,
+      ref title,
+    } => {
+      scaffold(input_dir, year, day, dry_run, download, title.as_deref())?;
+    }
+    Action::Calendar => print_calendar()?,
+    Action::Unscaffold { year, day, dry_run } => {
+      let mut plan = Plan::default();
+      plan.remove_day(year, day);
+
+      if plan.is_empty() {
+        eprintln!("Nothing to unscaffold");
+      } else if dry_run {
+        plan.print();
+      } else {
+        plan.apply()?;
+      }
+    }
+  }
+
+  Ok(ExitCode::SUCCESS)
+}
+
+/// Solves the given part of a puzzle and submits the answer, unless the
+/// ledger shows that it cannot be right. The outcome is recorded in the
+/// ledger, and a correct answer is also recorded in the answers store.
+fn submit(
+  client: &Client,
+  input_dir: &Path,
+  year: u32,
+  day: u32,
+  part: u8,
+) -> Result<ExitCode, Box<dyn Error>> {
+  let solver = registry::get(year, day)
+    .ok_or_else(|| format!("No solution for {year} day {day}"))?;
+  let data = read_to_string(single_input_path(input_dir, year, day)?)?;
+  let answers = solver.solve(&data, Some(part))?;
+  let answer = if part == 1 {
+    answers.part1
+  } else {
+    answers.part2
+  }
+  .ok_or("No answer was found")??
+  .to_string();
+
+  let mut ledger = Ledger::load(year, day)?;
+  let now = SystemTime::now();
+
+  if let Err(refusal) = ledger.check(part, &answer, now) {
+    eprintln!("Refusing to submit {answer}: {refusal}");
+    return Ok(ExitCode::FAILURE);
   }
+
+  let outcome = client.submit(year, day, part, &answer)?;
+  println!("{answer}: {outcome}");
+
+  ledger.record(part, &answer, &outcome, now);
+  ledger.save()?;
+
+  if outcome != Outcome::Correct {
+    return Ok(ExitCode::FAILURE);
+  }
+
+  let mut store = AnswerStore::load(year, None)?;
+  let mut answers = store.get(day).cloned().unwrap_or_default();
+  if part == 1 {
+    answers.part1 = Some(answer);
+  } else {
+    answers.part2 = Some(answer);
+  }
+  store.set(day, answers);
+  store.save()?;
+  eprintln!("Recorded answer in {}", store.file().display());
+
+  // The page of a solved puzzle includes the statement of the next part
+  if archive::load(year, day).is_some() {
+    archive::fetch(client, year, day)?;
+  }
+
+  Ok(ExitCode::SUCCESS)
 }
 
-impl Ord for Solution {
-  fn cmp(&self, other: &Self) -> Ordering {
-    self
-      .year
-      .cmp(&other.year)
-      .then_with(|| self.day.cmp(&other.day))
+/// Prints a line per event that has started, with a symbol per day for the
+/// stars earned, based on the answers recorded in the answers store.
+fn print_calendar() -> Result<(), Box<dyn Error>> {
+  let now = SystemTime::now();
+  let mut total = 0;
+
+  for year in calendar::years(now) {
+    let stars = calendar::stars(&AnswerStore::load(year, None)?, year);
+
+    let days = (1..)
+      .zip(&stars)
+      .map(|(day, stars)| match stars {
+        2 => '★',
+        1 => '☆',
+        _ if calendar::is_unlocked(year, day, now) => '·',
+        _ => ' ',
+      })
+      .collect::<String>();
+    let earned = stars.iter().map(|&stars| u32::from(stars)).sum::<u32>();
+
+    println!("{year} {days:<25} {earned:>2}/{}", stars.len() * 2);
+    total += earned;
   }
+
+  println!("Total: {total} stars");
+  Ok(())
 }
 
-fn main() {
-  let Args { year, day, format } = Args::parse();
+/// Scaffolds the boilerplate for the given year's event, or for the given
+/// day's puzzle. The title and example of the puzzle are taken from its page,
+/// which is archived unless this is a dry run.
+fn scaffold(
+  input_dir: &Path,
+  year: u32,
+  day: Option<u32>,
+  dry_run: bool,
+  download: bool,
+  title: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+  let mut plan = Plan::default();
+  let now = SystemTime::now();
 
-  // Filter solvers
-  let solvers = SOLVERS
-    .iter()
-    .filter(|solver| year.is_none_or(|y: u32| y == solver.year))
-    .filter(|solver| day.is_none_or(|d: u32| d == solver.day));
+  if let Some(day) = day {
+    calendar::check_day(year, day, now)?;
+
+    let page = match archive::load(year, day) {
+      Some(page) => page,
+      None if dry_run => Client::from_env()?.puzzle(year, day)?,
+      None => archive::fetch(&Client::from_env()?, year, day)?,
+    };
 
-  let mut solutions = Vec::new();
+    let title = title
+      .map(str::to_string)
+      .or_else(|| parse_title(&page))
+      .ok_or("Failed to find the puzzle title")?;
 
-  for Solver {
+    plan.day(year, day, &title, archive::example(&page).as_ref(), now)?;
+    if download {
+      plan.download(year, day, single_input_path(input_dir, year, day)?);
+    }
+  } else {
+    plan.year(year, now)?;
+  }
+
+  if plan.is_empty() {
+    eprintln!("Nothing to scaffold");
+  } else if dry_run {
+    plan.print();
+  } else {
+    plan.apply()?;
+  }
+
+  Ok(())
+}
+
+/// Returns the attempts to solve each of the given puzzles on every one of its
+/// inputs, unless one is given.
+fn attempts<'a>(
+  solvers: Vec<&'a Solver>,
+  input: Option<&Path>,
+  input_dir: &Path,
+) -> Vec<(&'a Solver, Input)> {
+  solvers
+    .into_iter()
+    .flat_map(|solver| {
+      input
+        .map_or_else(
+          || solver.inputs(input_dir),
+          |path| {
+            vec![Input {
+              name: None,
+              path: path.to_path_buf(),
+            }]
+          },
+        )
+        .into_iter()
+        .map(move |input| (solver, input))
+    })
+    .collect()
+}
+
+fn main() -> ExitCode {
+  let Args {
+    action,
     year,
     day,
-    path,
-    wrapper,
-  } in solvers
-  {
-    let data = read_to_string(path).unwrap();
-    let (part1, part2) = wrapper(data);
-    solutions.push(Solution {
-      year: *year,
-      day: *day,
-      part1,
-      part2,
+    format,
+    time,
+    check,
+    record,
+    part,
+    timeout,
+    jobs,
+    isolate_multithreaded,
+    input,
+    input_dir,
+  } = Args::parse();
+
+  let input_dir = input_dir.unwrap_or_else(registry::input_dir);
+
+  if let Some(action) = action {
+    return perform(&action, &input_dir).unwrap_or_else(|error| {
+      eprintln!("{error}");
+      ExitCode::FAILURE
     });
   }
 
+  registry::capture_panics();
+
+  // Filter solvers
+  let solvers = registry::all()
+    .iter()
+    .filter(|solver| year.as_ref().is_none_or(|y| y.contains(solver.year)))
+    .filter(|solver| day.as_ref().is_none_or(|d| d.contains(solver.day)))
+    .collect::<Vec<_>>();
+
+  if input.is_some() && solvers.len() != 1 {
+    Args::command()
+      .error(
+        clap::error::ErrorKind::ArgumentConflict,
+        format!(
+          "--input requires a single puzzle to be selected, but {} were",
+          solvers.len()
+        ),
+      )
+      .exit();
+  }
+
+  if format == OutputFormat::Junit && !check {
+    Args::command()
+      .error(
+        clap::error::ErrorKind::MissingRequiredArgument,
+        "--format junit requires --check",
+      )
+      .exit();
+  }
+
+  let attempts = attempts(solvers, input.as_deref(), &input_dir);
+
+  let verifier = match Verifier::new(check, record, &attempts) {
+    Ok(verifier) => verifier,
+    Err(error) => {
+      eprintln!("{error}");
+      return ExitCode::FAILURE;
+    }
+  };
+
+  let solve = |(solver, input): &(&Solver, Input)| {
+    let mut solution = timeout.map_or_else(
+      || solve(solver, input, part, time),
+      |timeout| solve_in_child_process(solver, input, part, time, timeout),
+    );
+    verifier.verify(&mut solution);
+
+    if format == OutputFormat::Ndjson {
+      println!("{}", serde_json::to_string(&solution).unwrap());
+    }
+
+    solution
+  };
+
+  let mut solutions = solve_all(attempts, jobs, isolate_multithreaded, solve);
   solutions.sort();
 
+  if let Err(error) = verifier.save() {
+    eprintln!("{error}");
+    return ExitCode::FAILURE;
+  }
+
   match format {
     OutputFormat::Table => {
-      let mut table = table!();
-
-      table.add_row(row![
-        cell!("Year"),
-        cell!("Day"),
-        cell!("Part 1"),
-        cell!("Part 2"),
-      ]);
-
-      for Solution {
-        year,
-        day,
-        part1,
-        part2,
-      } in solutions
-      {
-        table.add_row(row![
-          cell!(year),
-          cell!(format!("{:>3}", format!("{day:0>2}"))),
-          part1,
-          part2,
-        ]);
-      }
-
-      table.set_format(
-        FormatBuilder::new()
-          .column_separator('│')
-          .borders('│')
-          .separators(
-            &[LinePosition::Top],
-            LineSeparator::new('─', '┬', '┌', '┐'),
-          )
-          .separators(
-            &[LinePosition::Intern],
-            LineSeparator::new('─', '┼', '├', '┤'),
-          )
-          .separators(
-            &[LinePosition::Bottom],
-            LineSeparator::new('─', '┴', '└', '┘'),
-          )
-          .padding(3, 3)
-          .build(),
-      );
-      table.printstd();
+      print!("{}", report::to_table(&solutions, time, check));
     }
     OutputFormat::Json => {
       println!("{}", serde_json::to_string_pretty(&solutions).unwrap());
     }
+    OutputFormat::Csv => print!("{}", report::to_csv(&solutions, time, check)),
+    OutputFormat::Markdown => {
+      print!("{}", report::to_markdown(&solutions, time, check));
+    }
+    // Each solution was already printed as soon as it was solved
+    OutputFormat::Ndjson => {}
+    OutputFormat::Junit => print!("{}", report::to_junit(&solutions, part)),
+  }
+
+  let is_success = solutions.iter().all(Solution::is_success);
+
+  if is_success {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
   }
 }
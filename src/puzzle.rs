@@ -0,0 +1,206 @@
+//! This module defines the interface implemented by each day's puzzle, and the
+//! answers it produces.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Represents the answer to a part of a puzzle.
+///
+/// Integers that do not fit in an `i64` are big integers, which are kept in
+/// decimal and serialized as strings so that JSON consumers do not lose
+/// precision. Text that spans multiple lines, such as letters drawn on a grid,
+/// is treated as art.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+  Integer(i64),
+  BigInteger(#[serde(deserialize_with = "deserialize_big_integer")] String),
+  Text(String),
+  Art(String),
+}
+
+/// Deserializes a big integer from a string of decimal digits, with an
+/// optional minus sign.
+fn deserialize_big_integer<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<String, D::Error> {
+  let n = String::deserialize(deserializer)?;
+  let digits = n.strip_prefix('-').unwrap_or(&n);
+
+  if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+    return Err(serde::de::Error::custom(format!(
+      "invalid big integer {n:?}"
+    )));
+  }
+
+  Ok(n)
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Integer(n) => write!(f, "{n}"),
+      Self::BigInteger(n) => write!(f, "{n}"),
+      Self::Text(text) | Self::Art(text) => write!(f, "{text}"),
+    }
+  }
+}
+
+macro_rules! impl_from_integer {
+  ($($t:ty),*) => {
+    $(
+      impl From<$t> for Answer {
+        fn from(n: $t) -> Self {
+          i64::try_from(n)
+            .map_or_else(|_| Self::BigInteger(n.to_string()), Self::Integer)
+        }
+      }
+    )*
+  };
+}
+
+impl_from_integer!(
+  i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl From<String> for Answer {
+  fn from(text: String) -> Self {
+    let text = text.trim_matches('\n');
+
+    if text.contains('\n') {
+      Self::Art(text.to_string())
+    } else {
+      Self::Text(text.to_string())
+    }
+  }
+}
+
+impl From<&str> for Answer {
+  fn from(text: &str) -> Self {
+    Self::from(text.to_string())
+  }
+}
+
+/// Represents the solution to a day's puzzle.
+///
+/// The raw input is parsed once, and the parsed input is shared by both parts.
+/// Each day's module implements this trait on a unit struct named `Solution`,
+/// usually by delegating to its `parse`, `p1` and `p2` functions with the
+/// [`solution`] macro.
+pub trait Puzzle {
+  /// The parsed input, which may borrow from the raw input.
+  type Input<'a>;
+
+  /// The answer to the first part.
+  type P1: Into<Answer>;
+
+  /// The answer to the second part.
+  type P2: Into<Answer>;
+
+  /// Whether the solution spawns threads of its own, in which case it should
+  /// not compete with other puzzles for cores.
+  const MULTITHREADED: bool = false;
+
+  /// Parses the raw input.
+  fn parse(input: &str) -> Self::Input<'_>;
+
+  /// Solves the first part.
+  fn p1(input: &Self::Input<'_>) -> Self::P1;
+
+  /// Solves the second part.
+  fn p2(input: &Self::Input<'_>) -> Self::P2;
+}
+
+/// Declares the `Solution` of a day's module, implementing [`Puzzle`] by
+/// delegating to the module's `parse`, `p1` and `p2` functions.
+///
+/// The macro takes the parsed input type, which may borrow from the raw input
+/// with the `'a` lifetime, and the answer types of both parts. A trailing
+/// `multithreaded` marks solutions that spawn threads of their own.
+macro_rules! solution {
+  (@impl $input:ty, $p1:ty, $p2:ty, $multithreaded:literal) => {
+    pub struct Solution;
+
+    impl $crate::puzzle::Puzzle for Solution {
+      type Input<'a> = $input;
+      type P1 = $p1;
+      type P2 = $p2;
+
+      const MULTITHREADED: bool = $multithreaded;
+
+      fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+      }
+
+      fn p1(input: &Self::Input<'_>) -> Self::P1 {
+        p1(input)
+      }
+
+      fn p2(input: &Self::Input<'_>) -> Self::P2 {
+        p2(input)
+      }
+    }
+  };
+  ($input:ty, $p1:ty, $p2:ty, multithreaded) => {
+    $crate::puzzle::solution!(@impl $input, $p1, $p2, true);
+  };
+  ($input:ty, $p1:ty, $p2:ty) => {
+    $crate::puzzle::solution!(@impl $input, $p1, $p2, false);
+  };
+}
+
+pub(crate) use solution;
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  #[rstest]
+  #[case(Answer::from(42_u8), Answer::Integer(42))]
+  #[case(Answer::from(-42_i32), Answer::Integer(-42))]
+  #[case(Answer::from(i64::MIN), Answer::Integer(i64::MIN))]
+  #[case(
+    Answer::from(u64::MAX),
+    Answer::BigInteger("18446744073709551615".to_string())
+  )]
+  #[case(
+    Answer::from(i128::MIN),
+    Answer::BigInteger("-170141183460469231731687303715884105728".to_string())
+  )]
+  #[case(
+    Answer::from(u128::MAX),
+    Answer::BigInteger("340282366920938463463374607431768211455".to_string())
+  )]
+  #[case(Answer::from("abc"), Answer::Text("abc".to_string()))]
+  #[case(Answer::from("\n#.\n.#\n"), Answer::Art("#.\n.#".to_string()))]
+  fn test_from(#[case] answer: Answer, #[case] expected: Answer) {
+    assert_eq!(answer, expected);
+  }
+
+  #[rstest]
+  #[case(Answer::Integer(-42), r#"{"type":"integer","value":-42}"#)]
+  #[case(
+    Answer::from(u128::MAX),
+    r#"{"type":"big_integer","value":"340282366920938463463374607431768211455"}"#
+  )]
+  #[case(Answer::Text("abc".to_string()), r#"{"type":"text","value":"abc"}"#)]
+  #[case(
+    Answer::Art("#.\n.#".to_string()),
+    r##"{"type":"art","value":"#.\n.#"}"##
+  )]
+  fn test_serde_round_trip(#[case] answer: Answer, #[case] json: &str) {
+    assert_eq!(serde_json::to_string(&answer).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Answer>(json).unwrap(), answer);
+  }
+
+  #[rstest]
+  #[case(r#"{"type":"big_integer","value":"12a"}"#)]
+  #[case(r#"{"type":"big_integer","value":"-"}"#)]
+  #[case(r#"{"type":"big_integer","value":""}"#)]
+  fn test_deserialize_invalid_big_integer(#[case] json: &str) {
+    assert!(serde_json::from_str::<Answer>(json).is_err());
+  }
+}
@@ -0,0 +1,314 @@
+//! This module contains the registry of puzzles that have been attempted in
+//! the codebase.
+//!
+//! The list of puzzles is generated by the build script, which walks the
+//! `src/yYYYY/dDD.rs` files. It is exposed through the [`puzzles`] macro, which
+//! invokes a given macro with a `(year, day, year module, day module, title)`
+//! tuple for each puzzle, so that the binary and the benchmarks can all be
+//! generated from this single source of truth.
+
+use std::{
+  any::Any,
+  cell::{Cell, RefCell},
+  env,
+  fs::read_dir,
+  panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe},
+  path::{Path, PathBuf},
+  time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+  puzzle::{Answer, Puzzle},
+  util::serde::{deserialize_option, serialize_option, Format},
+};
+
+include!(concat!(env!("OUT_DIR"), "/puzzles.rs"));
+
+/// Represents the format of durations as a fractional number of microseconds.
+struct Microseconds;
+
+impl Format for Microseconds {
+  type Value = Duration;
+
+  fn serialize<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1_000_000.0)
+  }
+
+  fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Duration, D::Error> {
+    f64::deserialize(deserializer)
+      .map(|microseconds| Duration::from_secs_f64(microseconds / 1_000_000.0))
+  }
+}
+
+thread_local! {
+  /// The message of the last panic that occurred on this thread.
+  static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+
+  /// Whether panics on this thread are being caught to be reported along with
+  /// the solution.
+  static IS_CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Extracts the message from the payload of a panic.
+fn panic_payload_message(payload: &(dyn Any + Send)) -> String {
+  payload
+    .downcast_ref::<&str>()
+    .map(ToString::to_string)
+    .or_else(|| payload.downcast_ref::<String>().cloned())
+    .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Wraps the default panic hook, which prints to `stderr`, with one that
+/// records the message and location of the panics caught while solving.
+///
+/// This lets them be reported along with the solution. Any other panic is
+/// still reported by the default hook.
+pub fn capture_panics() {
+  let default_hook = take_hook();
+
+  set_hook(Box::new(move |info| {
+    if !IS_CATCHING_PANICS.get() {
+      default_hook(info);
+      return;
+    }
+
+    let mut message = panic_payload_message(info.payload());
+    if let Some(location) = info.location() {
+      message = format!("{message} at {location}");
+    }
+
+    PANIC_MESSAGE.with(|panic_message| {
+      panic_message.borrow_mut().replace(message);
+    });
+  }));
+}
+
+/// Runs the given function, catching any panic it raises. The error is the
+/// message recorded by the hook installed by [`capture_panics`], if any, or
+/// the payload of the panic otherwise.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+  IS_CATCHING_PANICS.set(true);
+  let result = catch_unwind(AssertUnwindSafe(f));
+  IS_CATCHING_PANICS.set(false);
+
+  result.map_err(|payload| {
+    PANIC_MESSAGE
+      .take()
+      .unwrap_or_else(|| panic_payload_message(payload.as_ref()))
+  })
+}
+
+/// Represents the time taken by each function required to solve a puzzle.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Durations {
+  #[serde(
+    serialize_with = "Microseconds::serialize",
+    deserialize_with = "Microseconds::deserialize"
+  )]
+  pub parse: Duration,
+  #[serde(
+    serialize_with = "serialize_option::<Microseconds, _>",
+    deserialize_with = "deserialize_option::<Microseconds, _>"
+  )]
+  pub part1: Option<Duration>,
+  #[serde(
+    serialize_with = "serialize_option::<Microseconds, _>",
+    deserialize_with = "deserialize_option::<Microseconds, _>"
+  )]
+  pub part2: Option<Duration>,
+}
+
+/// Represents the answers to the parts of a puzzle that were solved, or the
+/// message of the panic raised while solving them.
+pub struct Answers {
+  pub part1: Option<Result<Answer, String>>,
+  pub part2: Option<Result<Answer, String>>,
+  pub durations: Durations,
+}
+
+/// The environment variable that overrides the directory containing the
+/// puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Returns the directory containing the puzzle inputs, which is `input` unless
+/// overridden by the `AOC_INPUT_DIR` environment variable.
+pub fn input_dir() -> PathBuf {
+  env::var_os(INPUT_DIR_VAR)
+    .filter(|dir| !dir.is_empty())
+    .map_or_else(|| PathBuf::from("input"), PathBuf::from)
+}
+
+/// Returns the path to the input file for the given puzzle, within the given
+/// input directory.
+pub fn input_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+  dir
+    .join(format!("y{year}"))
+    .join(format!("d{day:0>2}"))
+    .with_extension("txt")
+}
+
+/// Returns the directory holding the named input files for the given puzzle,
+/// within the given input directory.
+pub fn named_inputs_dir(dir: &Path, year: u32, day: u32) -> PathBuf {
+  dir.join(format!("y{year}")).join(format!("d{day:0>2}"))
+}
+
+/// Represents an input file for a puzzle. It is named after the file if it is
+/// one of several inputs, e.g. those of different accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+  pub name: Option<String>,
+  pub path: PathBuf,
+}
+
+/// Represents a puzzle that has been attempted in the codebase, along with a
+/// type-erased function to solve it.
+pub struct Solver {
+  pub year: u32,
+  pub day: u32,
+  pub title: &'static str,
+  pub multithreaded: bool,
+  wrapper: fn(&str, Option<u8>) -> Result<Answers, String>,
+}
+
+impl Solver {
+  /// Returns the inputs for the puzzle within the given input directory.
+  ///
+  /// If there is a `yYYYY/dDD/` directory holding `.txt` files, each of them
+  /// is a named input, ordered by name. Otherwise, the single unnamed input is
+  /// the `yYYYY/dDD.txt` file, which may not exist.
+  pub fn inputs(&self, dir: &Path) -> Vec<Input> {
+    let mut inputs = read_dir(named_inputs_dir(dir, self.year, self.day))
+      .into_iter()
+      .flatten()
+      .filter_map(|entry| {
+        let path = entry.ok()?.path();
+        if !path.is_file()
+          || path.extension().is_none_or(|extension| extension != "txt")
+        {
+          return None;
+        }
+
+        Some(Input {
+          name: Some(path.file_stem()?.to_string_lossy().into_owned()),
+          path,
+        })
+      })
+      .collect::<Vec<_>>();
+
+    if inputs.is_empty() {
+      inputs.push(Input {
+        name: None,
+        path: input_path(dir, self.year, self.day),
+      });
+    }
+
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    inputs
+  }
+
+  /// Solves the puzzle for the given input. Only the given part is solved, if
+  /// any. Returns the message of the panic raised while parsing the input, if
+  /// any.
+  pub fn solve(
+    &self,
+    input: &str,
+    part: Option<u8>,
+  ) -> Result<Answers, String> {
+    (self.wrapper)(input, part)
+  }
+}
+
+/// Solves the given puzzle for the given input, measuring the time taken by
+/// each function. Only the given part is solved, if any.
+///
+/// Panics are caught for each function, so that a part that panics does not
+/// discard the answer to the other one.
+fn run<P: Puzzle>(data: &str, part: Option<u8>) -> Result<Answers, String> {
+  let instant = Instant::now();
+  let input = catch_panic(|| P::parse(data))?;
+  let parse_duration = instant.elapsed();
+
+  let (part1, part1_duration) = (part != Some(2))
+    .then(|| {
+      let instant = Instant::now();
+      let part1 = catch_panic(|| P::p1(&input).into());
+      let duration = part1.is_ok().then(|| instant.elapsed());
+      (part1, duration)
+    })
+    .unzip();
+
+  let (part2, part2_duration) = (part != Some(1))
+    .then(|| {
+      let instant = Instant::now();
+      let part2 = catch_panic(|| P::p2(&input).into());
+      let duration = part2.is_ok().then(|| instant.elapsed());
+      (part2, duration)
+    })
+    .unzip();
+
+  Ok(Answers {
+    part1,
+    part2,
+    durations: Durations {
+      parse: parse_duration,
+      part1: part1_duration.flatten(),
+      part2: part2_duration.flatten(),
+    },
+  })
+}
+
+macro_rules! solvers {
+  ($((
+    $year:literal,
+    $day:literal,
+    $year_mod:ident,
+    $day_mod:ident,
+    $title:literal
+  )),* $(,)?) => {
+    lazy_static! {
+      static ref SOLVERS: Vec<Solver> = vec![
+        $(Solver {
+          year: $year,
+          day: $day,
+          title: $title,
+          multithreaded:
+            <crate::$year_mod::$day_mod::Solution as Puzzle>::MULTITHREADED,
+          wrapper: run::<crate::$year_mod::$day_mod::Solution>,
+        }),*
+      ];
+    }
+  };
+}
+
+puzzles!(solvers);
+
+/// Returns all the puzzles that have been attempted, ordered by year and day.
+pub fn all() -> &'static [Solver] {
+  &SOLVERS
+}
+
+/// Returns the puzzle for the given year and day, if it has been attempted.
+pub fn get(year: u32, day: u32) -> Option<&'static Solver> {
+  SOLVERS
+    .iter()
+    .find(|solver| solver.year == year && solver.day == day)
+}
+
+/// Solves both parts of the puzzle for the given year and day, if it has been
+/// attempted.
+pub fn solve(
+  year: u32,
+  day: u32,
+  input: &str,
+) -> Option<Result<Answers, String>> {
+  get(year, day).map(|solver| solver.solve(input, None))
+}
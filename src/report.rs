@@ -0,0 +1,654 @@
+//! This module contains the solutions to the puzzles as reported by the
+//! runner, and the formats they are output in.
+//!
+//! The tabular formats (table, CSV and Markdown) share the same columns, which
+//! depend on the options and on the solutions. The `JUnit` format reports the
+//! verdict on each part's answer as a test case, for CI systems.
+
+use std::{cmp::Ordering, collections::BTreeMap, fmt, time::Duration};
+
+use prettytable::{
+  cell,
+  format::{FormatBuilder, LinePosition, LineSeparator},
+  table,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::Verdict, puzzle::Answer, registry::Durations};
+
+/// Represents the outcome of attempting to solve a puzzle.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+  Ok,
+  InputMissing,
+  Unreadable,
+  Panicked,
+  TimedOut,
+  ProcessFailed,
+}
+
+impl fmt::Display for Status {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let status = match self {
+      Self::Ok => "ok",
+      Self::InputMissing => "input missing",
+      Self::Unreadable => "unreadable",
+      Self::Panicked => "panicked",
+      Self::TimedOut => "timed out",
+      Self::ProcessFailed => "process failed",
+    };
+
+    write!(f, "{status}")
+  }
+}
+
+/// Represents the outcome of attempting to solve a puzzle on one of its
+/// inputs, along with the answers to the parts that were solved.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Solution {
+  pub year: u32,
+  pub day: u32,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub input: Option<String>,
+  pub status: Status,
+  pub part1: Option<Answer>,
+  pub part2: Option<Answer>,
+  #[serde(
+    rename = "durations_microseconds",
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub durations: Option<Durations>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub check: Option<Checks>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+}
+
+/// Represents the verdicts of comparing the answers to the solved parts of a
+/// puzzle against the known answers.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Checks {
+  pub part1: Option<Verdict>,
+  pub part2: Option<Verdict>,
+}
+
+impl Solution {
+  /// Checks if the puzzle was solved and none of its answers are known to be
+  /// wrong.
+  pub fn is_success(&self) -> bool {
+    self.status == Status::Ok
+      && self.check.as_ref().is_none_or(|Checks { part1, part2 }| {
+        !matches!(part1, Some(Verdict::Fail { .. }))
+          && !matches!(part2, Some(Verdict::Fail { .. }))
+      })
+  }
+}
+
+impl PartialOrd for Solution {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Solution {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self
+      .year
+      .cmp(&other.year)
+      .then_with(|| self.day.cmp(&other.day))
+      .then_with(|| self.input.cmp(&other.input))
+  }
+}
+
+/// Formats a duration as microseconds, for display in a table.
+fn format_microseconds(duration: Duration) -> String {
+  format!("{:.2}", duration.as_secs_f64() * 1_000_000.0)
+}
+
+/// Returns the header and rows of a report on the solutions, which is shared
+/// by the tabular output formats.
+///
+/// The durations and verdicts are only included if `time` and `check` are set,
+/// and the inputs and errors only if any solution has one.
+fn report(
+  solutions: &[Solution],
+  time: bool,
+  check: bool,
+) -> (Vec<&'static str>, Vec<Vec<String>>) {
+  let has_errors = solutions.iter().any(|solution| solution.error.is_some());
+  let has_inputs = solutions.iter().any(|solution| solution.input.is_some());
+
+  let mut header = vec!["Year", "Day"];
+  if has_inputs {
+    header.push("Input");
+  }
+  header.extend(["Status", "Part 1", "Part 2"]);
+  if time {
+    header.extend(["Parse (µs)", "Part 1 (µs)", "Part 2 (µs)"]);
+  }
+  if check {
+    header.extend(["Check 1", "Check 2"]);
+  }
+  if has_errors {
+    header.push("Error");
+  }
+
+  let rows = solutions
+    .iter()
+    .map(|solution| {
+      let mut row =
+        vec![solution.year.to_string(), format!("{:0>2}", solution.day)];
+      if has_inputs {
+        row.push(solution.input.clone().unwrap_or_default());
+      }
+      row.push(solution.status.to_string());
+      row.extend([&solution.part1, &solution.part2].map(|answer| {
+        answer.as_ref().map(ToString::to_string).unwrap_or_default()
+      }));
+
+      if time {
+        let durations = solution.durations.map_or([None; 3], |durations| {
+          [Some(durations.parse), durations.part1, durations.part2]
+        });
+        row.extend(durations.map(|duration| {
+          duration.map(format_microseconds).unwrap_or_default()
+        }));
+      }
+
+      if check {
+        let checks = solution
+          .check
+          .as_ref()
+          .map_or([&None, &None], |Checks { part1, part2 }| [part1, part2]);
+        row.extend(checks.map(|verdict| {
+          verdict
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+        }));
+      }
+
+      if has_errors {
+        row.push(solution.error.clone().unwrap_or_default());
+      }
+
+      row
+    })
+    .collect();
+
+  (header, rows)
+}
+
+/// Checks if the given column of a report holds durations, which are aligned
+/// to the right.
+fn is_duration_column(name: &str) -> bool {
+  name.ends_with("(µs)")
+}
+
+/// Renders the solutions in a table, for display in a terminal.
+pub fn to_table(solutions: &[Solution], time: bool, check: bool) -> String {
+  let (header, rows) = report(solutions, time, check);
+
+  let mut table = table!();
+  table.add_row(header.iter().map(|name| cell!(name)).collect());
+
+  for row in rows {
+    table.add_row(
+      header
+        .iter()
+        .zip(row)
+        .map(|(name, value)| match *name {
+          "Day" => cell!(format!("{value:>3}")),
+          name if is_duration_column(name) => cell!(r->value),
+          _ => cell!(value),
+        })
+        .collect(),
+    );
+  }
+
+  table.set_format(
+    FormatBuilder::new()
+      .column_separator('│')
+      .borders('│')
+      .separators(&[LinePosition::Top], LineSeparator::new('─', '┬', '┌', '┐'))
+      .separators(
+        &[LinePosition::Intern],
+        LineSeparator::new('─', '┼', '├', '┤'),
+      )
+      .separators(
+        &[LinePosition::Bottom],
+        LineSeparator::new('─', '┴', '└', '┘'),
+      )
+      .padding(3, 3)
+      .build(),
+  );
+  table.to_string()
+}
+
+/// Renders the solutions as CSV, with a header record.
+pub fn to_csv(solutions: &[Solution], time: bool, check: bool) -> String {
+  let (header, rows) = report(solutions, time, check);
+
+  let mut writer = csv::Writer::from_writer(vec![]);
+  writer.write_record(header).unwrap();
+  for row in rows {
+    writer.write_record(row).unwrap();
+  }
+
+  String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+/// Escapes the pipes and line breaks in a value, so that it fits in a cell of
+/// a markdown table.
+fn escape_markdown(value: &str) -> String {
+  value.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Renders the solutions in a markdown table.
+pub fn to_markdown(solutions: &[Solution], time: bool, check: bool) -> String {
+  let (header, rows) = report(solutions, time, check);
+
+  let mut lines = vec![format!("| {} |", header.join(" | "))];
+  lines.push(format!(
+    "|{}",
+    header
+      .iter()
+      .map(|name| if is_duration_column(name) {
+        " ---: |"
+      } else {
+        " --- |"
+      })
+      .collect::<String>()
+  ));
+
+  for row in rows {
+    let row = row
+      .iter()
+      .map(|value| escape_markdown(value))
+      .collect::<Vec<_>>();
+    lines.push(format!("| {} |", row.join(" | ")));
+  }
+
+  lines.join("\n") + "\n"
+}
+
+/// Escapes the special characters in a value, so that it fits in an XML
+/// attribute or text node.
+fn escape_xml(value: &str) -> String {
+  value
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&apos;")
+}
+
+/// Represents the counts of test cases in a `JUnit` test suite, by outcome.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct TestCounts {
+  tests: usize,
+  failures: usize,
+  errors: usize,
+  skipped: usize,
+}
+
+impl fmt::Display for TestCounts {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      r#"tests="{}" failures="{}" errors="{}" skipped="{}""#,
+      self.tests, self.failures, self.errors, self.skipped
+    )
+  }
+}
+
+/// Renders the verdicts of the solutions as `JUnit` XML, with a test suite per
+/// year and a test case per solved part.
+///
+/// A wrong answer is a failure, a part that could not be solved is an error,
+/// and an answer that is not in the answers store is skipped.
+pub fn to_junit(solutions: &[Solution], part: Option<u8>) -> String {
+  let mut suites = BTreeMap::<u32, (TestCounts, Vec<String>)>::new();
+  let parts = part.map_or_else(|| vec![1, 2], |part| vec![part]);
+
+  for solution in solutions {
+    let (counts, cases) = suites.entry(solution.year).or_default();
+
+    let mut classname = format!("y{}.d{:0>2}", solution.year, solution.day);
+    if let Some(input) = &solution.input {
+      classname = format!("{classname}.{input}");
+    }
+
+    for &part in &parts {
+      let (answer, verdict, duration) = if part == 1 {
+        (
+          &solution.part1,
+          solution
+            .check
+            .as_ref()
+            .and_then(|checks| checks.part1.as_ref()),
+          solution.durations.and_then(|durations| durations.part1),
+        )
+      } else {
+        (
+          &solution.part2,
+          solution
+            .check
+            .as_ref()
+            .and_then(|checks| checks.part2.as_ref()),
+          solution.durations.and_then(|durations| durations.part2),
+        )
+      };
+
+      let outcome = if let Some(answer) = answer {
+        match verdict {
+          Some(Verdict::Pass) => None,
+          Some(Verdict::Fail { expected }) => {
+            counts.failures += 1;
+            Some(format!(
+              r#"<failure message="expected {}, got {}"/>"#,
+              escape_xml(expected),
+              escape_xml(&answer.to_string())
+            ))
+          }
+          Some(Verdict::Unknown) | None => {
+            counts.skipped += 1;
+            Some(r#"<skipped message="no known answer"/>"#.to_string())
+          }
+        }
+      } else {
+        counts.errors += 1;
+        Some(format!(
+          r#"<error message="{}">{}</error>"#,
+          solution.status,
+          escape_xml(solution.error.as_deref().unwrap_or_default())
+        ))
+      };
+
+      counts.tests += 1;
+
+      let time = duration.map_or_else(String::new, |duration| {
+        format!(r#" time="{:.6}""#, duration.as_secs_f64())
+      });
+      let attributes = format!(
+        r#"classname="{}" name="part{part}"{time}"#,
+        escape_xml(&classname)
+      );
+
+      cases.push(outcome.map_or_else(
+        || format!("    <testcase {attributes}/>"),
+        |outcome| {
+          format!(
+            "    <testcase {attributes}>\n      {outcome}\n    </testcase>"
+          )
+        },
+      ));
+    }
+  }
+
+  let total =
+    suites
+      .values()
+      .fold(TestCounts::default(), |total, (counts, _)| TestCounts {
+        tests: total.tests + counts.tests,
+        failures: total.failures + counts.failures,
+        errors: total.errors + counts.errors,
+        skipped: total.skipped + counts.skipped,
+      });
+
+  let mut lines = vec![
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+    format!(r#"<testsuites name="aoc" {total}>"#),
+  ];
+  for (year, (counts, cases)) in suites {
+    lines.push(format!(r#"  <testsuite name="y{year}" {counts}>"#));
+    lines.extend(cases);
+    lines.push("  </testsuite>".to_string());
+  }
+  lines.push("</testsuites>".to_string());
+
+  lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  /// Returns a solution to the first puzzle of 2015, with the given answers,
+  /// verdicts and error.
+  fn solution(
+    part1: Option<i64>,
+    part2: Option<i64>,
+    check: Option<Checks>,
+    error: Option<&str>,
+  ) -> Solution {
+    Solution {
+      year: 2015,
+      day: 1,
+      input: None,
+      status: if error.is_some() {
+        Status::Panicked
+      } else {
+        Status::Ok
+      },
+      part1: part1.map(Answer::from),
+      part2: part2.map(Answer::from),
+      durations: Some(Durations {
+        parse: Duration::from_micros(1),
+        part1: part1.map(|_| Duration::from_micros(2)),
+        part2: part2.map(|_| Duration::from_micros(3)),
+      }),
+      check,
+      error: error.map(ToString::to_string),
+    }
+  }
+
+  #[rstest]
+  #[case(Some(3), Some(1))]
+  #[case(Some(3), None)]
+  #[case(None, None)]
+  fn test_serde_round_trip(
+    #[case] part1: Option<i64>,
+    #[case] part2: Option<i64>,
+  ) {
+    let solution = solution(part1, part2, None, None);
+
+    let json = serde_json::to_string(&solution).unwrap();
+
+    assert_eq!(serde_json::from_str::<Solution>(&json).unwrap(), solution);
+  }
+
+  #[rstest]
+  #[case("232", "232")]
+  #[case("a|b", "a\\|b")]
+  #[case("line 1\nline 2", "line 1<br>line 2")]
+  fn test_escape_markdown(#[case] value: &str, #[case] expected: &str) {
+    assert_eq!(escape_markdown(value), expected);
+  }
+
+  #[rstest]
+  #[case("232", "232")]
+  #[case("a & b", "a &amp; b")]
+  #[case("<tag>", "&lt;tag&gt;")]
+  #[case(r#""it's""#, "&quot;it&apos;s&quot;")]
+  fn test_escape_xml(#[case] value: &str, #[case] expected: &str) {
+    assert_eq!(escape_xml(value), expected);
+  }
+
+  #[rstest]
+  #[case(false, false, &["Year", "Day", "Status", "Part 1", "Part 2"])]
+  #[case(
+    true,
+    false,
+    &[
+      "Year",
+      "Day",
+      "Status",
+      "Part 1",
+      "Part 2",
+      "Parse (µs)",
+      "Part 1 (µs)",
+      "Part 2 (µs)",
+    ]
+  )]
+  #[case(
+    false,
+    true,
+    &["Year", "Day", "Status", "Part 1", "Part 2", "Check 1", "Check 2"]
+  )]
+  fn test_report_columns(
+    #[case] time: bool,
+    #[case] check: bool,
+    #[case] expected: &[&str],
+  ) {
+    let solutions = [solution(Some(3), Some(1), None, None)];
+
+    let (header, rows) = report(&solutions, time, check);
+
+    assert_eq!(header, expected);
+    assert!(rows.iter().all(|row| row.len() == header.len()));
+  }
+
+  #[test]
+  fn test_report_inputs_and_errors() {
+    let solutions = [
+      Solution {
+        input: Some("alice".to_string()),
+        ..solution(Some(3), None, None, Some("part 2 panicked"))
+      },
+      solution(Some(3), Some(1), None, None),
+    ];
+
+    let (header, rows) = report(&solutions, true, false);
+
+    assert_eq!(
+      header,
+      [
+        "Year",
+        "Day",
+        "Input",
+        "Status",
+        "Part 1",
+        "Part 2",
+        "Parse (µs)",
+        "Part 1 (µs)",
+        "Part 2 (µs)",
+        "Error",
+      ]
+    );
+    assert_eq!(
+      rows,
+      [
+        [
+          "2015",
+          "01",
+          "alice",
+          "panicked",
+          "3",
+          "",
+          "1.00",
+          "2.00",
+          "",
+          "part 2 panicked",
+        ],
+        ["2015", "01", "", "ok", "3", "1", "1.00", "2.00", "3.00", ""],
+      ]
+    );
+  }
+
+  #[test]
+  fn test_to_markdown() {
+    let solutions = [solution(Some(3), None, None, Some("a|b"))];
+
+    assert_eq!(
+      to_markdown(&solutions, false, false),
+      [
+        "| Year | Day | Status | Part 1 | Part 2 | Error |",
+        "| --- | --- | --- | --- | --- | --- |",
+        "| 2015 | 01 | panicked | 3 |  | a\\|b |",
+        "",
+      ]
+      .join("\n")
+    );
+  }
+
+  #[rstest]
+  #[case(Some(Verdict::Pass), None)]
+  #[case(
+    Some(Verdict::Fail { expected: "4".to_string() }),
+    Some(r#"<failure message="expected 4, got 3"/>"#)
+  )]
+  #[case(
+    Some(Verdict::Unknown),
+    Some(r#"<skipped message="no known answer"/>"#)
+  )]
+  #[case(None, Some(r#"<skipped message="no known answer"/>"#))]
+  fn test_to_junit_verdict(
+    #[case] verdict: Option<Verdict>,
+    #[case] outcome: Option<&str>,
+  ) {
+    let check = Checks {
+      part1: verdict,
+      part2: None,
+    };
+    let solutions = [solution(Some(3), None, Some(check), None)];
+
+    let junit = to_junit(&solutions, Some(1));
+
+    let case =
+      r#"<testcase classname="y2015.d01" name="part1" time="0.000002""#;
+    match outcome {
+      Some(outcome) => {
+        assert!(junit.contains(&format!("{case}>\n      {outcome}\n")));
+      }
+      None => assert!(junit.contains(&format!("{case}/>"))),
+    }
+  }
+
+  #[test]
+  fn test_to_junit_error() {
+    let solutions = [solution(None, None, None, Some("<boom>"))];
+
+    let junit = to_junit(&solutions, Some(2));
+
+    assert!(junit.contains(r#"<error message="panicked">&lt;boom&gt;</error>"#));
+  }
+
+  #[test]
+  fn test_to_junit_counts() {
+    let check = |part1, part2| Checks {
+      part1: Some(part1),
+      part2: Some(part2),
+    };
+    let fail = || Verdict::Fail {
+      expected: "0".to_string(),
+    };
+    let solutions = [
+      solution(Some(3), Some(1), Some(check(Verdict::Pass, fail())), None),
+      Solution {
+        year: 2016,
+        ..solution(Some(3), None, Some(check(Verdict::Unknown, fail())), None)
+      },
+      Solution {
+        year: 2016,
+        day: 2,
+        ..solution(None, None, None, Some("boom"))
+      },
+    ];
+
+    let junit = to_junit(&solutions, None);
+
+    assert!(junit.contains(
+      r#"<testsuites name="aoc" tests="6" failures="1" errors="3" skipped="1">"#
+    ));
+    assert!(junit.contains(
+      r#"<testsuite name="y2015" tests="2" failures="1" errors="0" skipped="0">"#
+    ));
+    assert!(junit.contains(
+      r#"<testsuite name="y2016" tests="4" failures="0" errors="3" skipped="1">"#
+    ));
+  }
+}
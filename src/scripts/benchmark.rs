@@ -93,3 +93,41 @@ impl Benchmark {
     .collect()
   }
 }
+
+/// Represents the benchmark results of the functions of a day's puzzle, in
+/// microseconds, along with the day's share of its year's total.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DayBenchmark {
+  pub parse: Option<f64>,
+  pub part1: Option<f64>,
+  pub part2: Option<f64>,
+  pub share: Option<f64>,
+}
+
+impl DayBenchmark {
+  /// Collects the results of the given benchmarks of a day's functions.
+  pub fn from_benchmarks<'a>(
+    benchmarks: impl IntoIterator<Item = &'a Benchmark>,
+  ) -> Self {
+    let mut day_benchmark = Self::default();
+
+    for benchmark in benchmarks {
+      let duration = Some(benchmark.duration_nanoseconds / 1_000.0);
+      match benchmark.function {
+        BenchmarkedFunction::Parse => day_benchmark.parse = duration,
+        BenchmarkedFunction::Part1 => day_benchmark.part1 = duration,
+        BenchmarkedFunction::Part2 => day_benchmark.part2 = duration,
+      }
+    }
+
+    day_benchmark
+  }
+
+  /// Returns the total duration of the functions that were benchmarked.
+  pub fn total(&self) -> f64 {
+    [self.parse, self.part1, self.part2]
+      .into_iter()
+      .flatten()
+      .sum()
+  }
+}
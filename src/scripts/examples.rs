@@ -0,0 +1,104 @@
+//! This module contains functions for prefilling the tests of a day's module
+//! with the example from the puzzle statement.
+
+use crate::archive::Example;
+
+/// The placeholder test case for the first part in the day template.
+const PLACEHOLDER: &str =
+  "  #[rstest]\n  #[case(\"example\", 0)]\n  fn test_p1(";
+
+/// Formats a string as a Rust string literal. Line breaks are kept as is, as
+/// in the examples of the existing tests.
+fn string_literal(value: &str) -> String {
+  format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Formats the expected answer of an example as a Rust literal, which is an
+/// integer if possible, or a string otherwise.
+fn answer_literal(answer: &str) -> String {
+  if answer.parse::<i128>().is_ok() {
+    answer.to_string()
+  } else {
+    string_literal(answer)
+  }
+}
+
+/// Returns the content of a day's module with the placeholder test case for
+/// the first part replaced by the given example, or `None` if there is no
+/// placeholder.
+pub fn with_example(module_content: &str, example: &Example) -> Option<String> {
+  if !module_content.contains(PLACEHOLDER) {
+    return None;
+  }
+
+  let input = string_literal(&example.input);
+  let answer = example
+    .answer
+    .as_deref()
+    .map_or_else(|| "0".to_string(), answer_literal);
+
+  let case = if example.input.contains('\n') {
+    format!("#[case(\n    {input},\n    {answer}\n  )]")
+  } else {
+    format!("#[case({input}, {answer})]")
+  };
+
+  Some(module_content.replace(
+    PLACEHOLDER,
+    &format!(
+      "  #[rstest]\n  // TODO: Review the example extracted from the puzzle \
+       statement\n  {case}\n  fn test_p1("
+    ),
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+  use crate::{archive, fixtures::PAGE};
+
+  /// The module of a day that was just scaffolded.
+  const MODULE: &str = include_str!("../../templates/day/d{{day}}.rs");
+
+  #[rstest]
+  #[case("(())", Some("0"), "#[case(\"(())\", 0)]")]
+  #[case("))(", None, "#[case(\"))(\", 0)]")]
+  #[case("abcdef", Some("-609043"), "#[case(\"abcdef\", -609043)]")]
+  #[case(
+    "a \"b\"\nc\\d",
+    Some("ABC"),
+    "#[case(\n    \"a \\\"b\\\"\nc\\\\d\",\n    \"ABC\"\n  )]"
+  )]
+  fn test_with_example(
+    #[case] input: &str,
+    #[case] answer: Option<&str>,
+    #[case] expected: &str,
+  ) {
+    let example = Example {
+      input: input.to_string(),
+      answer: answer.map(ToString::to_string),
+    };
+    let content = with_example(MODULE, &example).unwrap();
+
+    assert!(content.contains(&format!(
+      "  #[rstest]\n  // TODO: Review the example extracted from the puzzle \
+       statement\n  {expected}\n  fn test_p1("
+    )));
+    assert!(content.contains("#[case(\"example\", 0)]\n  fn test_p2("));
+    assert_eq!(with_example(&content, &example), None);
+  }
+
+  #[test]
+  fn test_with_example_from_page() {
+    let example = archive::example(PAGE).unwrap();
+    let content = with_example(MODULE, &example).unwrap();
+
+    assert!(content.contains(
+      "  #[case(\n    \
+       \"1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\"\
+       ,\n    24000\n  )]\n  fn test_p1("
+    ));
+  }
+}
@@ -8,21 +8,34 @@ use std::{
 
 use num_format::{Locale, ToFormattedString};
 
-use crate::util::parse::ParseOps;
+use super::{benchmark::DayBenchmark, table::Table};
+use crate::archive;
 
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_sign_loss)]
 fn format_duration(duration: f64) -> String {
-  let integer = duration.trunc() as u64;
-  let fractional = duration.fract();
+  let hundredths = (duration * 100.0).round() as u64;
 
   format!(
-    "{}.{}",
-    integer.to_formatted_string(&Locale::en),
-    (fractional * 100.0).round() as u8
+    "{}.{:02}",
+    (hundredths / 100).to_formatted_string(&Locale::en),
+    hundredths % 100
   )
 }
 
+/// Formats the share of a day in its year's total as a percentage.
+fn format_share(share: f64) -> String {
+  format!("{:.1}%", share * 100.0)
+}
+
+/// Returns the benchmark cell of an entry, which is only filled in once the
+/// puzzles are complete.
+fn benchmark(is_complete: bool, duration: Option<f64>) -> String {
+  duration
+    .filter(|_| is_complete)
+    .map_or_else(|| "-".to_string(), format_duration)
+}
+
 /// Adds/updates an entry for the given year to the README, in the solutions
 /// table. If a benchmark duration is provided, it is added to the entry.
 pub fn update_year_entry_in_readme(
@@ -33,142 +46,235 @@ pub fn update_year_entry_in_readme(
   let readme_path = Path::new("README.md");
   let readme_content = read_to_string(readme_path).unwrap();
 
-  let year_entry_index = format!("| [{year}](./src/y{year}/) |");
-  let benchmark = if let Some(duration) = duration_milliseconds
-    && is_complete
-  {
-    format!("{} |", format_duration(duration))
-  } else {
-    "- |".to_string()
-  };
-  let year_entry = format!("{year_entry_index} {benchmark}");
-
-  let mut modified_readme_content = vec![];
-
-  if readme_content.contains(&year_entry_index) {
-    for line in readme_content.lines() {
-      if line.starts_with(&year_entry_index) {
-        let mut columns = line.split('|').collect::<Vec<_>>();
-        let benchmark = benchmark.as_str().replace('|', "");
-        columns[2] = &benchmark;
-        modified_readme_content.push(columns.join("|").to_string());
-      } else {
-        modified_readme_content.push(line.to_string());
-      }
-    }
-  } else {
-    let mut is_in_solutions_section = false;
-    let mut is_in_solutions_table = false;
-    let mut did_insert_entry = false;
-
-    for line in readme_content.lines() {
-      if line == "## Solutions" {
-        is_in_solutions_section = true;
-      } else if line.starts_with('#') {
-        is_in_solutions_section = false;
-      }
+  let modified_readme_content =
+    with_year_entry(&readme_content, year, is_complete, duration_milliseconds);
+  let mut readme_file = File::create(readme_path).unwrap();
+  write!(readme_file, "{modified_readme_content}").unwrap();
+  println!("Updated README with entry for year '{year}'");
+}
 
-      if is_in_solutions_table && !did_insert_entry {
-        if let Some(line_year) = line.iter_unsigned::<u32>().next() {
-          if line_year > year && !did_insert_entry {
-            modified_readme_content.push(year_entry.clone());
-            did_insert_entry = true;
-          }
-        }
-
-        if !line.starts_with('|') && !did_insert_entry {
-          is_in_solutions_section = false;
-          modified_readme_content.push(year_entry.clone());
-          did_insert_entry = true;
-        }
-      }
+/// Returns the content of the README with an added/updated entry for the given
+/// year in the solutions table.
+pub fn with_year_entry(
+  readme_content: &str,
+  year: u32,
+  is_complete: bool,
+  duration_milliseconds: Option<f64>,
+) -> String {
+  let key = format!("[{year}](./src/y{year}/)");
+  let benchmark = benchmark(is_complete, duration_milliseconds);
 
-      is_in_solutions_table = line.starts_with('|') && is_in_solutions_section;
+  Table::edit(readme_content, "Year", |table| {
+    let column = table.column("Benchmark").unwrap();
 
-      modified_readme_content.push(line.to_string());
+    if let Some(row) = table.row_mut(&key) {
+      row[column] = benchmark;
+    } else {
+      let mut row = vec![String::new(); table.header.len()];
+      row[0] = key;
+      row[column] = benchmark;
+      table.upsert(row);
     }
-  }
-
-  let modified_readme_content: String = modified_readme_content.join("\n");
-  let mut readme_file = File::create(readme_path).unwrap();
-  write!(readme_file, "{modified_readme_content}").unwrap();
-  println!("Updated README with entry for year '{year}'");
+  })
+  .unwrap_or_else(|| {
+    eprintln!("No solutions table found in the README");
+    readme_content.to_string()
+  })
 }
 
 /// Adds/updates an entry for the given day to the year README, in the solutions
-/// table. The title is used as the display name for the entry.
+/// table. The title is used as the display name for the entry, and defaults to
+/// the title from the archived puzzle page.
 pub fn update_day_entry_in_year_readme(
   year: u32,
   day: u32,
   title: Option<&str>,
   is_complete: bool,
-  duration_microseconds: Option<f64>,
+  benchmark: Option<&DayBenchmark>,
 ) {
   let readme_path = Path::new("src").join(format!("y{year}")).join("README.md");
   let readme_content = read_to_string(&readme_path).unwrap();
 
-  let day_entry_index = format!("| [{day:0>2}](./d{day:0>2}.rs) |");
-  let benchmark = if let Some(duration) = duration_microseconds
-    && is_complete
-  {
-    format!("{} |", format_duration(duration))
-  } else {
-    "- |".to_string()
+  let modified_readme_content =
+    with_day_entry(&readme_content, year, day, title, is_complete, benchmark);
+  let mut readme_file = File::create(&readme_path).unwrap();
+  write!(readme_file, "{modified_readme_content}").unwrap();
+  println!("Updated README for year '{year}' with entry for day '{day:0>2}'");
+}
+
+/// Returns the content of the year README with an added/updated entry for the
+/// given day in the solutions table.
+///
+/// The benchmark is shown with a column for each function, their total, and
+/// the day's share of the year's total.
+/// The title is used as the display name for the entry, and defaults to the
+/// title from the archived puzzle page.
+pub fn with_day_entry(
+  readme_content: &str,
+  year: u32,
+  day: u32,
+  title: Option<&str>,
+  is_complete: bool,
+  benchmark: Option<&DayBenchmark>,
+) -> String {
+  let key = format!("[{day:0>2}](./d{day:0>2}.rs)");
+  let benchmark = benchmark.filter(|_| is_complete);
+  let duration = |duration: Option<f64>| {
+    duration.map_or_else(|| "-".to_string(), format_duration)
   };
+  let cells = [
+    ("Parse", duration(benchmark.and_then(|b| b.parse))),
+    ("Part 1", duration(benchmark.and_then(|b| b.part1))),
+    ("Part 2", duration(benchmark.and_then(|b| b.part2))),
+    ("Total", duration(benchmark.map(DayBenchmark::total))),
+    (
+      "Share",
+      benchmark
+        .and_then(|b| b.share)
+        .map_or_else(|| "-".to_string(), format_share),
+    ),
+  ];
 
-  let mut modified_readme_content = vec![];
-
-  if readme_content.contains(&day_entry_index) {
-    for line in readme_content.lines() {
-      if line.starts_with(&day_entry_index) {
-        let mut columns = line.split('|').collect::<Vec<_>>();
-        let benchmark = benchmark.as_str().replace('|', "");
-        columns[3] = &benchmark;
-        modified_readme_content.push(columns.join("|").to_string());
-      } else {
-        modified_readme_content.push(line.to_string());
+  Table::edit(readme_content, "Day", |table| {
+    let cells =
+      cells.map(|(column, cell)| (table.column(column).unwrap(), cell));
+    let set_cells = |row: &mut Vec<String>| {
+      for (column, cell) in &cells {
+        row[*column].clone_from(cell);
       }
+    };
+
+    if let Some(row) = table.row_mut(&key) {
+      set_cells(row);
+      return;
     }
-  } else {
-    let day_entry = if let Some(title) = title {
-      format!(
-        "{day_entry_index} [{title}](https://adventofcode.com/{year}/day/{day}) | \
-         {benchmark}",
-      )
-    } else {
+
+    let Some(title) = title
+      .map(ToString::to_string)
+      .or_else(|| archive::title(year, day))
+    else {
       eprintln!("No title provided for day '{day:0>2}'");
       return;
     };
 
-    let mut is_in_table = false;
-    let mut did_insert_entry = false;
-
-    for line in readme_content.lines() {
-      if is_in_table && !did_insert_entry {
-        if let Some(line_day) = line.iter_unsigned::<u32>().next() {
-          if line_day > day && !did_insert_entry {
-            modified_readme_content.push(day_entry.clone());
-            did_insert_entry = true;
-          }
-        }
-
-        if !line.starts_with('|') && !did_insert_entry {
-          modified_readme_content.push(day_entry.clone());
-          did_insert_entry = true;
-        }
-      }
+    let mut row = vec![String::new(); table.header.len()];
+    row[0] = key;
+    row[table.column("Challenge").unwrap()] =
+      format!("[{title}](https://adventofcode.com/{year}/day/{day})");
+    set_cells(&mut row);
+    table.upsert(row);
+  })
+  .unwrap_or_else(|| {
+    eprintln!("No solutions table found in the README for year '{year}'");
+    readme_content.to_string()
+  })
+}
 
-      is_in_table = line.starts_with('|');
-      modified_readme_content.push(line.to_string());
-    }
+/// Returns the content of the year README without the entry for the given
+/// day, or `None` if there is no such entry.
+pub fn without_day_entry(readme_content: &str, day: u32) -> Option<String> {
+  let key = format!("[{day:0>2}](./d{day:0>2}.rs)");
 
-    if !did_insert_entry {
-      modified_readme_content.push(day_entry);
-    }
+  let mut did_remove_entry = false;
+  let modified_readme_content = Table::edit(readme_content, "Day", |table| {
+    did_remove_entry = table.remove(&key);
+  })?;
+
+  did_remove_entry.then_some(modified_readme_content)
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+  use crate::fixtures::{README, YEAR_README};
+
+  /// Returns the cells of the entry for the given day in the year README.
+  fn day_entry(readme_content: &str, day: u32) -> Vec<&str> {
+    readme_content
+      .lines()
+      .find(|line| line.starts_with(&format!("| [{day:0>2}](./d{day:0>2}.rs)")))
+      .unwrap()
+      .split('|')
+      .map(str::trim)
+      .filter(|cell| !cell.is_empty())
+      .collect()
   }
 
-  let modified_readme_content: String = modified_readme_content.join("\n");
-  let mut readme_file = File::create(&readme_path).unwrap();
-  write!(readme_file, "{modified_readme_content}").unwrap();
-  println!("Updated README for year '{year}' with entry for day '{day:0>2}'");
+  #[rstest]
+  #[case(3, None, true, [
+    "[03](./d03.rs)",
+    "[Perfectly Spherical Houses in a Vacuum](https://adventofcode.com/2015/day/3)",
+    "1.50",
+    "20.25",
+    "1,234.57",
+    "1,256.32",
+    "12.5%",
+  ])]
+  #[case(3, None, false, [
+    "[03](./d03.rs)",
+    "[Perfectly Spherical Houses in a Vacuum](https://adventofcode.com/2015/day/3)",
+    "-",
+    "-",
+    "-",
+    "-",
+    "-",
+  ])]
+  #[case(6, Some("Probably a Fire Hazard"), true, [
+    "[06](./d06.rs)",
+    "[Probably a Fire Hazard](https://adventofcode.com/2015/day/6)",
+    "1.50",
+    "20.25",
+    "1,234.57",
+    "1,256.32",
+    "12.5%",
+  ])]
+  fn test_with_day_entry(
+    #[case] day: u32,
+    #[case] title: Option<&str>,
+    #[case] is_complete: bool,
+    #[case] expected: [&str; 7],
+  ) {
+    let benchmark = DayBenchmark {
+      parse: Some(1.5),
+      part1: Some(20.25),
+      part2: Some(1_234.567),
+      share: Some(0.125),
+    };
+
+    let content = with_day_entry(
+      YEAR_README,
+      2015,
+      day,
+      title,
+      is_complete,
+      Some(&benchmark),
+    );
+
+    assert_eq!(day_entry(&content, day), expected);
+  }
+
+  #[rstest]
+  #[case(1)]
+  #[case(3)]
+  #[case(5)]
+  fn test_without_day_entry(#[case] day: u32) {
+    let entry = YEAR_README
+      .lines()
+      .find(|line| line.starts_with(&format!("| [{day:0>2}](./d{day:0>2}.rs)")))
+      .unwrap();
+
+    assert_eq!(
+      without_day_entry(YEAR_README, day),
+      Some(YEAR_README.replace(&format!("{entry}\n"), ""))
+    );
+  }
+
+  #[rstest]
+  #[case(YEAR_README, 6)]
+  #[case(README, 1)]
+  fn test_without_day_entry_missing(#[case] content: &str, #[case] day: u32) {
+    assert_eq!(without_day_entry(content, day), None);
+  }
 }
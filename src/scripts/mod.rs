@@ -2,90 +2,52 @@
 
 #![allow(clippy::print_stdout)]
 pub mod benchmark;
-pub mod declarations;
+pub mod examples;
 pub mod markdown;
+pub mod scaffold;
+pub mod table;
 
-use std::path::Path;
-
-use benchmark::Benchmark;
-use clap::Parser;
+use benchmark::{Benchmark, DayBenchmark};
 use itertools::Itertools;
 use markdown::{update_day_entry_in_year_readme, update_year_entry_in_readme};
 
-#[derive(Parser, Debug)]
-#[clap(version)]
-struct ScaffoldYearArgs {
-  year: u32,
-}
-
-pub fn scaffold_year() {
-  let ScaffoldYearArgs { year } = ScaffoldYearArgs::parse();
-  declarations::add_module_declaration(
-    &format!("y{year}"),
-    &Path::new("src").join("lib.rs"),
-  )
-  .unwrap();
-  markdown::update_year_entry_in_readme(year, false, None);
-}
-
-#[derive(Parser, Debug)]
-#[clap(version)]
-struct ScaffoldDayArgs {
-  year: u32,
-  day: u32,
-  title: String,
-}
-
-pub fn scaffold_day() {
-  let ScaffoldDayArgs { year, day, title } = ScaffoldDayArgs::parse();
-  declarations::add_module_declaration(
-    &format!("d{day:0>2}"),
-    &Path::new("src").join(format!("y{year}")).join("mod.rs"),
-  )
-  .unwrap();
-  markdown::update_day_entry_in_year_readme(
-    year,
-    day,
-    Some(&title),
-    false,
-    None,
-  );
-}
+use crate::calendar;
 
 pub fn update_documentation_with_benchmarks() {
   let benchmarks = Benchmark::load_all();
 
   for (year, benchmarks_in_year) in &benchmarks.iter().chunk_by(|b| b.year) {
-    let mut year_duration_milliseconds = 0.0;
-    let mut day_count = 0;
+    let mut day_benchmarks = benchmarks_in_year
+      .chunk_by(|b| b.day)
+      .into_iter()
+      .map(|(day, benchmarks_in_day)| {
+        (day, DayBenchmark::from_benchmarks(benchmarks_in_day))
+      })
+      .collect::<Vec<_>>();
 
-    for (day, benchmarks_in_day) in &benchmarks_in_year
-      .collect::<Vec<_>>()
+    let year_duration_microseconds = day_benchmarks
       .iter()
-      .chunk_by(|b| b.day)
-    {
-      let day_duration_microseconds = benchmarks_in_day
-        .collect::<Vec<_>>()
-        .iter()
-        .map(|b| b.duration_nanoseconds / 1_000.0)
-        .sum::<f64>();
+      .map(|(_, day_benchmark)| day_benchmark.total())
+      .sum::<f64>();
+
+    for (day, day_benchmark) in &mut day_benchmarks {
+      // A year whose benchmarks all took no time has no meaningful shares
+      day_benchmark.share = (year_duration_microseconds > 0.0)
+        .then(|| day_benchmark.total() / year_duration_microseconds);
 
       update_day_entry_in_year_readme(
         year,
-        day,
+        *day,
         None,
         true,
-        Some(day_duration_microseconds),
+        Some(day_benchmark),
       );
-
-      day_count += 1;
-      year_duration_milliseconds += day_duration_microseconds / 1_000.0;
     }
 
     update_year_entry_in_readme(
       year,
-      day_count == 25,
-      Some(year_duration_milliseconds),
+      day_benchmarks.len() == calendar::days_in_year(year) as usize,
+      Some(year_duration_microseconds / 1_000.0),
     );
   }
 }
@@ -0,0 +1,487 @@
+//! This module contains functions for scaffolding the boilerplate for a new
+//! year's event or day's puzzle, from the templates in the `templates/`
+//! directory, and for backing out a scaffolded day's puzzle.
+//!
+//! The changes are planned in memory before any file is written, so that they
+//! can be previewed with a dry run. Only the files that are missing or lack an
+//! entry for the puzzle are changed, so scaffolding twice has no effect.
+
+use std::{
+  collections::BTreeMap,
+  error::Error,
+  fs::{create_dir_all, read_dir, read_to_string, remove_file, write},
+  path::{Path, PathBuf},
+  time::SystemTime,
+};
+
+use super::{
+  examples::with_example,
+  markdown::{with_day_entry, with_year_entry, without_day_entry},
+};
+use crate::{archive::Example, calendar, client::Client};
+
+/// Returns the name and content of each file in the given template within the
+/// given root directory, ordered by name.
+fn template(
+  root: &Path,
+  name: &str,
+) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+  let mut files = read_dir(root.join("templates").join(name))?
+    .map(|entry| {
+      let path = entry?.path();
+      let name = path.file_name().unwrap().to_string_lossy().into_owned();
+      Ok((name, read_to_string(&path)?))
+    })
+    .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+  files.sort();
+  Ok(files)
+}
+
+/// Renders a template by replacing its `{{year}}`, `{{day}}` and `{{title}}`
+/// placeholders. The day is padded with a zero, as in module names.
+fn render(template: &str, year: u32, day: Option<u32>, title: &str) -> String {
+  let mut rendered = template
+    .replace("{{year}}", &year.to_string())
+    .replace("{{title}}", title);
+
+  if let Some(day) = day {
+    rendered = rendered.replace("{{day}}", &format!("{day:0>2}"));
+  }
+
+  rendered
+}
+
+/// Represents the scaffolding planned so far, as the new content of each file
+/// it changes, or `None` for the files it deletes, and the inputs it
+/// downloads.
+///
+/// The files are those of the repository in the root directory, which is the
+/// current directory by default.
+#[derive(Default)]
+pub struct Plan {
+  root: PathBuf,
+  files: BTreeMap<PathBuf, Option<String>>,
+  downloads: Vec<(u32, u32, PathBuf)>,
+}
+
+impl Plan {
+  /// Returns an empty plan for the repository in the given root directory.
+  pub fn new(root: PathBuf) -> Self {
+    Self {
+      root,
+      ..Self::default()
+    }
+  }
+
+  /// Returns the directory of the given year's event.
+  fn year_directory(&self, year: u32) -> PathBuf {
+    self.root.join("src").join(format!("y{year}"))
+  }
+
+  /// Returns the content of the given file as it will be after the planned
+  /// changes, if it exists.
+  fn read(&self, path: &Path) -> Option<String> {
+    self
+      .files
+      .get(path)
+      .cloned()
+      .unwrap_or_else(|| read_to_string(path).ok())
+  }
+
+  /// Plans to write the given content to the given file.
+  fn write(&mut self, path: PathBuf, content: String) {
+    self.files.insert(path, Some(content));
+  }
+
+  /// Plans to delete the given file.
+  fn delete(&mut self, path: PathBuf) {
+    self.files.insert(path, None);
+  }
+
+  /// Plans to render the files of the given template into the given directory,
+  /// except for those that already exist. The given function is applied to the
+  /// content of each rendered file.
+  fn render_template(
+    &mut self,
+    template_name: &str,
+    directory: &Path,
+    year: u32,
+    day: Option<u32>,
+    title: &str,
+    process: impl Fn(String) -> String,
+  ) -> Result<(), Box<dyn Error>> {
+    for (name, content) in template(&self.root, template_name)? {
+      let path = directory.join(render(&name, year, day, title));
+
+      if self.read(&path).is_none() {
+        self.write(path, process(render(&content, year, day, title)));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Plans the scaffolding of the given year's event, which must have started
+  /// at the given time.
+  pub fn year(
+    &mut self,
+    year: u32,
+    now: SystemTime,
+  ) -> Result<(), Box<dyn Error>> {
+    calendar::check_year(year, now)?;
+
+    let directory = self.year_directory(year);
+    self
+      .render_template("year", &directory, year, None, "", |content| content)?;
+
+    let readme_path = self.root.join("README.md");
+    let readme_content = self.read(&readme_path).unwrap_or_default();
+    if !readme_content.contains(&format!("| [{year}](./src/y{year}/) |")) {
+      self.write(
+        readme_path,
+        with_year_entry(&readme_content, year, false, None),
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Plans the scaffolding of the given day's puzzle, which must be unlocked
+  /// at the given time, and of its year's event if needed. The test case for
+  /// the first part is prefilled with the given example, if any.
+  pub fn day(
+    &mut self,
+    year: u32,
+    day: u32,
+    title: &str,
+    example: Option<&Example>,
+    now: SystemTime,
+  ) -> Result<(), Box<dyn Error>> {
+    calendar::check_day(year, day, now)?;
+
+    let directory = self.year_directory(year);
+    if self.read(&directory.join("README.md")).is_none() {
+      self.year(year, now)?;
+    }
+
+    self.render_template(
+      "day",
+      &directory,
+      year,
+      Some(day),
+      title,
+      |content| {
+        example
+          .and_then(|example| with_example(&content, example))
+          .unwrap_or(content)
+      },
+    )?;
+
+    let readme_path = directory.join("README.md");
+    let readme_content = self.read(&readme_path).unwrap_or_default();
+    if !readme_content.contains(&format!("| [{day:0>2}](./d{day:0>2}.rs) |")) {
+      self.write(
+        readme_path,
+        with_day_entry(&readme_content, year, day, Some(title), false, None),
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Plans to back out the scaffolding of the given day's puzzle, by deleting
+  /// its module along with its entry in the year README.
+  /// The input and the archived page of the puzzle are kept.
+  pub fn remove_day(&mut self, year: u32, day: u32) {
+    let directory = self.year_directory(year);
+
+    let module_path = directory.join(format!("d{day:0>2}.rs"));
+    if self.read(&module_path).is_some() {
+      self.delete(module_path);
+    }
+
+    let readme_path = directory.join("README.md");
+    if let Some(content) = self
+      .read(&readme_path)
+      .and_then(|content| without_day_entry(&content, day))
+    {
+      self.write(readme_path, content);
+    }
+  }
+
+  /// Plans to download the input for the given puzzle into the given file,
+  /// unless it already exists.
+  pub fn download(&mut self, year: u32, day: u32, path: PathBuf) {
+    if !path.exists() {
+      self.downloads.push((year, day, path));
+    }
+  }
+
+  /// Returns the path, current content and new content of each file that
+  /// will be changed, where missing content means that the file does not
+  /// exist.
+  fn changes(
+    &self,
+  ) -> impl Iterator<Item = (&Path, Option<String>, Option<&str>)> {
+    self.files.iter().filter_map(|(path, content)| {
+      let current = read_to_string(path).ok();
+      (current.as_deref() != content.as_deref()).then_some((
+        path.as_path(),
+        current,
+        content.as_deref(),
+      ))
+    })
+  }
+
+  /// Checks if nothing needs to be scaffolded.
+  pub fn is_empty(&self) -> bool {
+    self.changes().next().is_none() && self.downloads.is_empty()
+  }
+
+  /// Prints the planned changes, along with the lines that will be removed
+  /// from and added to the existing files.
+  pub fn print(&self) {
+    for (path, current, content) in self.changes() {
+      let (Some(current), Some(content)) = (&current, content) else {
+        let action = if content.is_some() {
+          "create"
+        } else {
+          "delete"
+        };
+        println!("{action} {}", path.display());
+        continue;
+      };
+
+      println!("update {}", path.display());
+
+      let current_lines = current.lines().collect::<Vec<_>>();
+      let lines = content.lines().collect::<Vec<_>>();
+      for line in current_lines.iter().filter(|line| !lines.contains(line)) {
+        println!("  - {line}");
+      }
+      for line in lines.iter().filter(|line| !current_lines.contains(line)) {
+        println!("  + {line}");
+      }
+    }
+
+    for (_, _, path) in &self.downloads {
+      println!("download {}", path.display());
+    }
+  }
+
+  /// Applies the planned changes, downloading the inputs with the client
+  /// configured from the environment. The inputs are downloaded first, so that
+  /// the sources are left untouched if a download fails.
+  pub fn apply(&self) -> Result<(), Box<dyn Error>> {
+    if !self.downloads.is_empty() {
+      let client = Client::from_env()?;
+
+      for (year, day, path) in &self.downloads {
+        client.download_input(*year, *day, path)?;
+        println!("Downloaded input to {}", path.display());
+      }
+    }
+
+    for (path, current, content) in self.changes() {
+      let Some(content) = content else {
+        remove_file(path)?;
+        println!("Deleted {}", path.display());
+        continue;
+      };
+
+      if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+      }
+
+      write(path, content)?;
+      if current.is_some() {
+        println!("Updated {}", path.display());
+      } else {
+        println!("Created {}", path.display());
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+  use tempfile::{tempdir, TempDir};
+
+  use super::*;
+  use crate::fixtures::{README, YEAR_README};
+
+  /// A past year that has nothing scaffolded in the test repository, besides
+  /// its entry in the README.
+  const YEAR: u32 = 2016;
+
+  /// Returns the time at which the scaffolding is planned in the tests, once
+  /// half of the puzzles of the 2025 event are unlocked.
+  fn now() -> SystemTime {
+    calendar::unlock_time(2025, 6)
+  }
+
+  /// Returns a temporary repository with the templates, and with the README
+  /// and first day's module of the 2015 event.
+  fn repository() -> TempDir {
+    let root = tempdir().unwrap();
+
+    for (path, content) in [
+      ("README.md", README),
+      ("src/y2015/README.md", YEAR_README),
+      ("src/y2015/d01.rs", "//! # Not Quite Lisp\n"),
+      (
+        "templates/day/d{{day}}.rs",
+        include_str!("../../templates/day/d{{day}}.rs"),
+      ),
+      (
+        "templates/year/README.md",
+        include_str!("../../templates/year/README.md"),
+      ),
+    ] {
+      let path = root.path().join(path);
+      create_dir_all(path.parent().unwrap()).unwrap();
+      write(path, content).unwrap();
+    }
+
+    root
+  }
+
+  /// Returns the paths of the files changed by the plan, relative to its root.
+  fn paths(plan: &Plan) -> Vec<&str> {
+    plan
+      .files
+      .keys()
+      .map(|path| path.strip_prefix(&plan.root).unwrap().to_str().unwrap())
+      .collect()
+  }
+
+  /// Returns the planned content of the given file, relative to the root.
+  fn content<'a>(plan: &'a Plan, path: &str) -> Option<&'a str> {
+    plan.files[&plan.root.join(path)].as_deref()
+  }
+
+  #[test]
+  fn test_year() {
+    let root = repository();
+    let readme = README
+      .lines()
+      .filter(|line| !line.starts_with("| [2016]("))
+      .collect::<Vec<_>>();
+    write(root.path().join("README.md"), readme.join("\n") + "\n").unwrap();
+
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.year(YEAR, now()).unwrap();
+
+    assert_eq!(paths(&plan), ["README.md", "src/y2016/README.md"]);
+    assert!(content(&plan, "README.md")
+      .unwrap()
+      .contains("| [2016](./src/y2016/) |"));
+    assert!(content(&plan, "src/y2016/README.md")
+      .unwrap()
+      .starts_with("# Advent of Code [2016](https://adventofcode.com/2016)\n"));
+    assert!(!plan.is_empty());
+  }
+
+  #[test]
+  fn test_year_scaffolded() {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.year(2015, now()).unwrap();
+
+    assert!(plan.is_empty());
+  }
+
+  #[test]
+  fn test_day() {
+    let root = repository();
+    let example = Example {
+      input: "(())".to_string(),
+      answer: Some("0".to_string()),
+    };
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.day(YEAR, 3, "Title", Some(&example), now()).unwrap();
+
+    assert_eq!(paths(&plan), ["src/y2016/README.md", "src/y2016/d03.rs"]);
+    assert!(content(&plan, "src/y2016/README.md").unwrap().contains(
+      "| [03](./d03.rs) | [Title](https://adventofcode.com/2016/day/3) |"
+    ));
+
+    let module = content(&plan, "src/y2016/d03.rs").unwrap();
+    assert!(module.starts_with("//! # Title\n"));
+    assert!(module.contains("solution!(Input, OutputP1, OutputP2);"));
+    assert!(module.contains("  #[case(\"(())\", 0)]\n  fn test_p1("));
+  }
+
+  #[test]
+  fn test_day_scaffolded() {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.day(2015, 1, "Not Quite Lisp", None, now()).unwrap();
+
+    assert!(plan.is_empty());
+  }
+
+  #[rstest]
+  #[case(2014)]
+  #[case(2026)]
+  fn test_year_missing(#[case] year: u32) {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+
+    assert!(plan.year(year, now()).is_err());
+    assert!(plan.is_empty());
+  }
+
+  #[rstest]
+  #[case(2014, 1)]
+  #[case(YEAR, 0)]
+  #[case(YEAR, 26)]
+  #[case(2025, 7)]
+  #[case(2026, 1)]
+  fn test_day_missing(#[case] year: u32, #[case] day: u32) {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+
+    assert!(plan.day(year, day, "Title", None, now()).is_err());
+    assert!(plan.is_empty());
+  }
+
+  #[test]
+  fn test_remove_day() {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.remove_day(2015, 1);
+
+    assert_eq!(paths(&plan), ["src/y2015/README.md", "src/y2015/d01.rs"]);
+    assert_eq!(content(&plan, "src/y2015/d01.rs"), None);
+
+    let readme = content(&plan, "src/y2015/README.md").unwrap();
+    assert!(!readme.contains("./d01.rs"));
+    assert!(readme.contains("| [02](./d02.rs) |"));
+  }
+
+  #[test]
+  fn test_remove_day_missing() {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.remove_day(YEAR, 1);
+
+    assert!(plan.is_empty());
+  }
+
+  #[rstest]
+  #[case("README.md", 0)]
+  #[case("input/y2016/d01.txt", 1)]
+  fn test_download(#[case] path: &str, #[case] expected: usize) {
+    let root = repository();
+    let mut plan = Plan::new(root.path().to_path_buf());
+    plan.download(YEAR, 1, root.path().join(path));
+
+    assert_eq!(plan.downloads.len(), expected);
+    assert_eq!(plan.is_empty(), expected == 0);
+  }
+}
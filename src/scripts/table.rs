@@ -0,0 +1,393 @@
+//! This module contains a small model of Markdown tables, for updating the
+//! tables in the READMEs without disturbing the rest of their content.
+//!
+//! Tables are found by the name of their first column, and are rendered with
+//! their columns aligned in the same way as Prettier does, so that updating a
+//! formatted README does not require formatting it again.
+
+use std::ops::Range;
+
+/// Represents the alignment of a column, as given by its delimiter cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+  None,
+  Left,
+  Center,
+  Right,
+}
+
+impl Alignment {
+  /// Parses the alignment from a delimiter cell, such as `:---:`.
+  fn parse(cell: &str) -> Option<Self> {
+    let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+    if dashes.is_empty() || dashes.chars().any(|c| c != '-') {
+      return None;
+    }
+
+    Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+      (false, false) => Self::None,
+      (true, false) => Self::Left,
+      (true, true) => Self::Center,
+      (false, true) => Self::Right,
+    })
+  }
+
+  /// Returns the delimiter cell for a column of the given width.
+  fn delimiter(self, width: usize) -> String {
+    match self {
+      Self::None => "-".repeat(width),
+      Self::Left => format!(":{}", "-".repeat(width - 1)),
+      Self::Center => format!(":{}:", "-".repeat(width - 2)),
+      Self::Right => format!("{}:", "-".repeat(width - 1)),
+    }
+  }
+
+  /// Pads a cell to the given width according to the alignment.
+  fn pad(self, cell: &str, width: usize) -> String {
+    let padding = width.saturating_sub(cell.chars().count());
+
+    match self {
+      Self::None | Self::Left => format!("{cell}{}", " ".repeat(padding)),
+      Self::Center => format!(
+        "{}{cell}{}",
+        " ".repeat(padding / 2),
+        " ".repeat(padding - padding / 2)
+      ),
+      Self::Right => format!("{}{cell}", " ".repeat(padding)),
+    }
+  }
+}
+
+/// Splits a table line into its trimmed cells. Escaped pipes (`\|`) are kept
+/// within their cell.
+fn split_cells(line: &str) -> Vec<String> {
+  let line = line.trim();
+  let line = line.strip_prefix('|').unwrap_or(line);
+  let line = line.strip_suffix('|').unwrap_or(line);
+
+  let mut cells = vec![];
+  let mut cell = String::new();
+  let mut is_escaped = false;
+
+  for c in line.chars() {
+    if c == '|' && !is_escaped {
+      cells.push(cell.trim().to_string());
+      cell.clear();
+    } else {
+      cell.push(c);
+    }
+
+    is_escaped = c == '\\' && !is_escaped;
+  }
+
+  cells.push(cell.trim().to_string());
+  cells
+}
+
+/// Represents a Markdown table, made of a header, the alignment of each
+/// column, and the rows. The first cell of each row is its key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+  pub header: Vec<String>,
+  pub alignments: Vec<Alignment>,
+  pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+  /// Parses a table from its lines, which are the header, the delimiter row
+  /// and then the rows. Missing cells are left empty.
+  pub fn parse(lines: &[&str]) -> Option<Self> {
+    let [header, delimiter, rows @ ..] = lines else {
+      return None;
+    };
+
+    let header = split_cells(header);
+    let alignments = split_cells(delimiter)
+      .iter()
+      .map(|cell| Alignment::parse(cell))
+      .collect::<Option<Vec<_>>>()?;
+    if alignments.len() != header.len() {
+      return None;
+    }
+
+    let rows = rows
+      .iter()
+      .map(|row| {
+        let mut cells = split_cells(row);
+        cells.resize(header.len(), String::new());
+        cells
+      })
+      .collect();
+
+    Some(Self {
+      header,
+      alignments,
+      rows,
+    })
+  }
+
+  /// Finds the first table in the given content whose first column has the
+  /// given name. Returns the byte range of the table's lines, excluding the
+  /// final line break, along with the table.
+  pub fn find(
+    content: &str,
+    first_column: &str,
+  ) -> Option<(Range<usize>, Self)> {
+    let mut lines = vec![];
+    let mut offset = 0;
+
+    // Group the consecutive lines that start with a pipe, with their ranges
+    for line in content.split_inclusive('\n') {
+      let text = line.trim_end_matches(['\n', '\r']);
+
+      if text.starts_with('|') {
+        lines.push((offset..offset + text.len(), text));
+      } else {
+        if let Some(found) = Self::find_in(&lines, first_column) {
+          return Some(found);
+        }
+        lines.clear();
+      }
+
+      offset += line.len();
+    }
+
+    Self::find_in(&lines, first_column)
+  }
+
+  /// Parses the given group of lines as a table, if its first column has the
+  /// given name.
+  fn find_in(
+    lines: &[(Range<usize>, &str)],
+    first_column: &str,
+  ) -> Option<(Range<usize>, Self)> {
+    let (first, _) = lines.first()?;
+    let (last, _) = lines.last()?;
+
+    let table =
+      Self::parse(&lines.iter().map(|(_, line)| *line).collect::<Vec<_>>())?;
+
+    (table.header.first().map(String::as_str) == Some(first_column))
+      .then_some((first.start..last.end, table))
+  }
+
+  /// Returns the given content with the first table whose first column has
+  /// the given name edited by the given function, or `None` if there is no
+  /// such table. Everything but the table is preserved as is.
+  pub fn edit(
+    content: &str,
+    first_column: &str,
+    edit: impl FnOnce(&mut Self),
+  ) -> Option<String> {
+    let (range, mut table) = Self::find(content, first_column)?;
+    edit(&mut table);
+
+    let mut content = content.to_string();
+    content.replace_range(range, &table.render());
+    Some(content)
+  }
+
+  /// Returns the index of the first column whose name starts with the given
+  /// prefix.
+  pub fn column(&self, prefix: &str) -> Option<usize> {
+    self.header.iter().position(|name| name.starts_with(prefix))
+  }
+
+  /// Returns the row with the given key, if there is one.
+  pub fn row_mut(&mut self, key: &str) -> Option<&mut Vec<String>> {
+    self.rows.iter_mut().find(|row| row[0] == key)
+  }
+
+  /// Replaces the row with the same key as the given row, or inserts it before
+  /// the first row with a greater key. Missing cells are left empty.
+  pub fn upsert(&mut self, mut row: Vec<String>) {
+    row.resize(self.header.len(), String::new());
+
+    if let Some(existing) = self.row_mut(&row[0]) {
+      *existing = row;
+      return;
+    }
+
+    let index = self
+      .rows
+      .iter()
+      .position(|existing| existing[0] > row[0])
+      .unwrap_or(self.rows.len());
+    self.rows.insert(index, row);
+  }
+
+  /// Removes the row with the given key, returning whether there was one.
+  pub fn remove(&mut self, key: &str) -> bool {
+    let count = self.rows.len();
+    self.rows.retain(|row| row[0] != key);
+    self.rows.len() != count
+  }
+
+  /// Renders the table with its columns aligned, without a final line break.
+  pub fn render(&self) -> String {
+    let widths = (0..self.header.len())
+      .map(|column| {
+        self
+          .rows
+          .iter()
+          .chain([&self.header])
+          .map(|row| row[column].chars().count())
+          .max()
+          .unwrap_or_default()
+          .max(3)
+      })
+      .collect::<Vec<_>>();
+
+    let render_row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let pad_row = |row: &[String]| {
+      render_row(
+        row
+          .iter()
+          .zip(&self.alignments)
+          .zip(&widths)
+          .map(|((cell, alignment), &width)| alignment.pad(cell, width))
+          .collect(),
+      )
+    };
+
+    let mut lines = vec![pad_row(&self.header)];
+    lines.push(render_row(
+      self
+        .alignments
+        .iter()
+        .zip(&widths)
+        .map(|(alignment, &width)| alignment.delimiter(width))
+        .collect(),
+    ));
+    lines.extend(self.rows.iter().map(|row| pad_row(row)));
+
+    lines.join("\n")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+  use crate::fixtures::{EMPTY_YEAR_README, README, YEAR_README};
+
+  #[rstest]
+  #[case(README, "Year")]
+  #[case(YEAR_README, "Day")]
+  fn test_round_trip(#[case] content: &str, #[case] first_column: &str) {
+    assert_eq!(
+      Table::edit(content, first_column, |_| {}).as_deref(),
+      Some(content)
+    );
+  }
+
+  #[rstest]
+  #[case(README, "Day")]
+  #[case(YEAR_README, "Year")]
+  #[case(YEAR_README, "Challenge")]
+  fn test_find_missing(#[case] content: &str, #[case] first_column: &str) {
+    assert_eq!(Table::find(content, first_column), None);
+  }
+
+  #[test]
+  fn test_find() {
+    let (range, table) = Table::find(README, "Year").unwrap();
+
+    assert!(README[range].starts_with("|                 Year |"));
+    assert_eq!(table.header, ["Year", "Benchmark (ms)"]);
+    assert_eq!(table.alignments, [Alignment::Right, Alignment::Right]);
+    assert_eq!(table.rows[0], ["[2015](./src/y2015/)", "-"]);
+  }
+
+  #[test]
+  fn test_update_realigns() {
+    let content = Table::edit(README, "Year", |table| {
+      let column = table.column("Benchmark").unwrap();
+      table.row_mut("[2016](./src/y2016/)").unwrap()[column] =
+        "1,234,567.89".to_string();
+    })
+    .unwrap();
+
+    assert!(content.contains(
+      "|                 Year | Benchmark (ms) |\n| -------------------: | \
+       -------------: |\n| [2015](./src/y2015/) |              - |\n| \
+       [2016](./src/y2016/) |   1,234,567.89 |\n"
+    ));
+    assert_eq!(content.len(), README.len());
+  }
+
+  #[test]
+  fn test_upsert_inserts_in_order() {
+    let content = Table::edit(EMPTY_YEAR_README, "Day", |table| {
+      for day in ["02", "01", "03"] {
+        table.upsert(vec![
+          format!("[{day}](./d{day}.rs)"),
+          format!("[Day {day}](https://adventofcode.com/2025/day/{day})"),
+        ]);
+      }
+    })
+    .unwrap();
+
+    let (_, table) = Table::find(&content, "Day").unwrap();
+    assert_eq!(
+      table
+        .rows
+        .iter()
+        .map(|row| row[0].as_str())
+        .collect::<Vec<_>>(),
+      ["[01](./d01.rs)", "[02](./d02.rs)", "[03](./d03.rs)"]
+    );
+    assert!(content.contains(
+      "| [01](./d01.rs) | [Day 01](https://adventofcode.com/2025/day/01) \
+       |            |             |             |            |       |\n"
+    ));
+  }
+
+  #[test]
+  fn test_upsert_replaces() {
+    let content = Table::edit(YEAR_README, "Day", |table| {
+      let mut row = table.row_mut("[03](./d03.rs)").unwrap().clone();
+      row[5] = "1.00".to_string();
+      table.upsert(row);
+    })
+    .unwrap();
+
+    assert!(content.contains(
+      "| [03](./d03.rs) | [Perfectly Spherical Houses in a Vacuum](https://adventofcode.com/2015/day/3) \
+       |          - |           - |           - |       1.00 |  0.4% |\n"
+    ));
+    assert_eq!(content.len(), YEAR_README.len());
+  }
+
+  #[test]
+  fn test_remove() {
+    let content = Table::edit(YEAR_README, "Day", |table| {
+      assert!(table.remove("[03](./d03.rs)"));
+      assert!(!table.remove("[03](./d03.rs)"));
+    })
+    .unwrap();
+
+    assert!(!content.contains("./d03.rs"));
+    assert!(content.contains("./d04.rs"));
+  }
+
+  #[rstest]
+  #[case("| a \\| b | c |", &["a \\| b", "c"])]
+  #[case("|a|b|", &["a", "b"])]
+  #[case("| a | |", &["a", ""])]
+  fn test_split_cells(#[case] line: &str, #[case] expected: &[&str]) {
+    assert_eq!(split_cells(line), expected);
+  }
+
+  #[rstest]
+  #[case(":-:", Some(Alignment::Center))]
+  #[case(":--", Some(Alignment::Left))]
+  #[case("--:", Some(Alignment::Right))]
+  #[case("---", Some(Alignment::None))]
+  #[case("Day", None)]
+  #[case("::", None)]
+  fn test_alignment(#[case] cell: &str, #[case] expected: Option<Alignment>) {
+    assert_eq!(Alignment::parse(cell), expected);
+  }
+}
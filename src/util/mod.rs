@@ -3,5 +3,6 @@ pub mod integer;
 pub mod md5;
 pub mod parse;
 pub mod point;
+pub mod serde;
 pub mod slice;
 pub mod thread;
@@ -0,0 +1,71 @@
+//! Adapters to serialize values in a custom format with `serde`, for use in
+//! the `serialize_with` and `deserialize_with` field attributes.
+//!
+//! A format is implemented once for a type, e.g. durations as microseconds,
+//! and [`serialize_option`] and [`deserialize_option`] apply it to optional
+//! values of that type:
+//!
+//! ```ignore
+//! #[serde(
+//!   serialize_with = "serialize_option::<Microseconds, _>",
+//!   deserialize_with = "deserialize_option::<Microseconds, _>"
+//! )]
+//! part1: Option<Duration>,
+//! ```
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Represents a custom format in which the values of a type are serialized.
+pub trait Format {
+  type Value;
+
+  /// Serializes a value in the format.
+  fn serialize<S: Serializer>(
+    value: &Self::Value,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error>;
+
+  /// Deserializes a value from the format.
+  fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self::Value, D::Error>;
+}
+
+/// Represents a value borrowed to be serialized in the given format.
+struct Formatted<'a, F: Format>(&'a F::Value);
+
+impl<F: Format> Serialize for Formatted<'_, F> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    F::serialize(self.0, serializer)
+  }
+}
+
+/// Represents a value deserialized from the given format.
+struct Parsed<F: Format>(F::Value);
+
+impl<'de, F: Format> Deserialize<'de> for Parsed<F> {
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    F::deserialize(deserializer).map(Self)
+  }
+}
+
+/// Serializes an optional value in the given format.
+#[allow(clippy::ref_option)]
+pub fn serialize_option<F: Format, S: Serializer>(
+  value: &Option<F::Value>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match value {
+    Some(value) => serializer.serialize_some(&Formatted::<F>(value)),
+    None => serializer.serialize_none(),
+  }
+}
+
+/// Deserializes an optional value from the given format.
+pub fn deserialize_option<'de, F: Format, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Option<F::Value>, D::Error> {
+  Option::<Parsed<F>>::deserialize(deserializer)
+    .map(|value| value.map(|Parsed(value)| value))
+}
@@ -7,6 +7,7 @@
 //! > This module is originally sourced from [here](https://github.com/maneatingape/advent-of-code-rust/blob/main/src/util/thread.rs)
 //! > and is under the MIT license.
 use std::{
+  panic::resume_unwind,
   sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering::Relaxed},
   thread::{available_parallelism, scope, ScopedJoinHandle},
 };
@@ -16,6 +17,14 @@ pub fn threads() -> usize {
   available_parallelism().unwrap().get()
 }
 
+/// Wait for a scoped thread to finish, propagating its panic if it panicked,
+/// rather than losing its results.
+fn join<R>(handle: ScopedJoinHandle<'_, R>) -> R {
+  handle
+    .join()
+    .unwrap_or_else(|payload| resume_unwind(payload))
+}
+
 /// Spawn `n` scoped threads, where `n` is the available parallelism.
 pub fn spawn<F, R>(f: F) -> Vec<R>
 where
@@ -30,10 +39,7 @@ where
       handles.push(handle);
     }
 
-    handles
-      .into_iter()
-      .flat_map(ScopedJoinHandle::join)
-      .collect()
+    handles.into_iter().map(join).collect()
   })
 }
 
@@ -49,7 +55,21 @@ where
   R: Send,
   T: Sync,
 {
-  let threads = threads();
+  spawn_parallel_iterator_with_threads(threads(), items, f)
+}
+
+/// Spawn the given number of scoped threads that each receive a work stealing
+/// iterator.
+pub fn spawn_parallel_iterator_with_threads<F, R, T>(
+  threads: usize,
+  items: &[T],
+  f: F,
+) -> Vec<R>
+where
+  F: Fn(ParIter<'_, T>) -> R + Copy + Send,
+  R: Send,
+  T: Sync,
+{
   let size = items.len().div_ceil(threads);
 
   // Initially divide work as evenly as possible among each worker thread.
@@ -70,10 +90,7 @@ where
       handles.push(handle);
     }
 
-    handles
-      .into_iter()
-      .flat_map(ScopedJoinHandle::join)
-      .collect()
+    handles.into_iter().map(join).collect()
   })
 }
 
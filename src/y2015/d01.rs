@@ -6,6 +6,8 @@
 //! - all other characters are ignored, and the floor doesn't change, and are
 //!   treated as 0
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Vec<i32> {
   input
     .chars()
@@ -36,6 +38,8 @@ pub fn p2(input: &[i32]) -> usize {
   unreachable!("Should have reached basement already")
 }
 
+solution!(Vec<i32>, i32, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
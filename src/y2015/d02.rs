@@ -6,7 +6,7 @@
 //! Dimensions are sorted in ascending order to facilitate calculations on the
 //! smallest side.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub struct Gift {
   dimensions: [u32; 3],
@@ -44,6 +44,8 @@ pub fn p2(input: &[Gift]) -> u32 {
   input.iter().map(Gift::ribbon_length).sum()
 }
 
+solution!(Vec<Gift>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -9,7 +9,10 @@
 
 use std::collections::HashSet;
 
-use crate::util::point::{Point, ORIGIN};
+use crate::{
+  puzzle::solution,
+  util::point::{Point, ORIGIN},
+};
 
 pub fn parse(input: &str) -> Vec<Point> {
   input.trim().bytes().map(Point::from).collect()
@@ -42,6 +45,8 @@ pub fn p2(input: &[Point]) -> usize {
   deliver(input, |index| index % 2 == 0)
 }
 
+solution!(Vec<Point>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
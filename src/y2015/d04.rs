@@ -23,9 +23,12 @@
 
 use std::sync::atomic::{AtomicU32, Ordering};
 
-use crate::util::{
-  md5::hash,
-  thread::{spawn, AtomicIter},
+use crate::{
+  puzzle::solution,
+  util::{
+    md5::hash,
+    thread::{spawn, AtomicIter},
+  },
 };
 
 pub struct State {
@@ -109,6 +112,8 @@ pub fn p2(input: &State) -> u32 {
   input.six_zeros_num.load(Ordering::Relaxed)
 }
 
+solution!(State, u32, u32, multithreaded);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
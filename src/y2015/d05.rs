@@ -24,6 +24,8 @@
 //! > expressions, I was curious as to how one could go about solving it without
 //! > them.
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Vec<&[u8]> {
   input.trim().lines().map(str::as_bytes).collect()
 }
@@ -101,6 +103,8 @@ pub fn p2(input: &[&[u8]]) -> usize {
   input.iter().filter(criteria2).count()
 }
 
+solution!(Vec<&'a [u8]>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
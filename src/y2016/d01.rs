@@ -7,9 +7,12 @@
 
 use std::collections::HashSet;
 
-use crate::util::{
-  parse::ParseOps,
-  point::{Point, ORIGIN, UP},
+use crate::{
+  puzzle::solution,
+  util::{
+    parse::ParseOps,
+    point::{Point, ORIGIN, UP},
+  },
 };
 pub struct Movement {
   turn: char,
@@ -69,6 +72,8 @@ pub fn p2(input: &[Movement]) -> i32 {
   unreachable!()
 }
 
+solution!(Vec<Movement>, i32, i32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -4,7 +4,10 @@
 //! Always check if the new position is within the grid and if the letter at the
 //! new position is not a space.
 
-use crate::util::{grid::Grid, point::Point};
+use crate::{
+  puzzle::solution,
+  util::{grid::Grid, point::Point},
+};
 
 type Input = Vec<Vec<Point>>;
 
@@ -75,6 +78,8 @@ pub fn p2(input: &Input) -> String {
   .code(input)
 }
 
+solution!(Input, String, String);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -6,7 +6,7 @@
 //! For part 2, we need to parse the triangles
 //! vertically, so we need to split the input into 3 separate iterators.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<u32> {
   input.iter_unsigned().collect()
@@ -35,6 +35,8 @@ pub fn p2(input: &[u32]) -> usize {
     + count_valid_triangles(input.iter().copied().skip(2).step_by(3))
 }
 
+solution!(Vec<u32>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -12,7 +12,7 @@
 
 use std::str::FromStr;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub struct Room {
   name: String,
@@ -114,6 +114,8 @@ pub fn p2(input: &[Room]) -> u32 {
     .unwrap()
 }
 
+solution!(Vec<Room>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
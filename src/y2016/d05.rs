@@ -7,9 +7,12 @@
 
 use std::sync::Mutex;
 
-use crate::util::{
-  md5::hash,
-  thread::{spawn, AtomicIter},
+use crate::{
+  puzzle::solution,
+  util::{
+    md5::hash,
+    thread::{spawn, AtomicIter},
+  },
 };
 
 pub struct PasswordSeed {
@@ -118,6 +121,8 @@ pub fn p2(input: &[u32]) -> String {
   format!("{password:08x}")
 }
 
+solution!(Vec<u32>, String, String, multithreaded);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
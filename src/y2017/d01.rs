@@ -4,7 +4,7 @@
 //! same position with an offset. This is done by cloning the input and
 //! rotating/shifting it by the offset, then zipping the two vectors together.
 
-use crate::util::parse::ParseByte;
+use crate::{puzzle::solution, util::parse::ParseByte};
 
 pub struct Captcha {
   digits: Vec<u8>,
@@ -38,6 +38,8 @@ pub fn p2(input: &Captcha) -> u32 {
   input.solve(input.digits.len() / 2)
 }
 
+solution!(Captcha, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
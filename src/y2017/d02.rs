@@ -6,7 +6,7 @@
 
 use itertools::Itertools;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub struct SpreadSheet {
   rows: Vec<Vec<u32>>,
@@ -69,6 +69,8 @@ pub fn p2(input: &SpreadSheet) -> u32 {
   input.get_evenly_divisible_pair_checksum()
 }
 
+solution!(SpreadSheet, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -14,9 +14,12 @@
 //! values of the neighbours.
 use std::collections::HashMap;
 
-use crate::util::{
-  parse::ParseOps,
-  point::{Point, RIGHT},
+use crate::{
+  puzzle::solution,
+  util::{
+    parse::ParseOps,
+    point::{Point, RIGHT},
+  },
 };
 
 pub fn parse(input: &str) -> u32 {
@@ -61,6 +64,8 @@ pub fn p2(input: &u32) -> u32 {
   spiral[&pos]
 }
 
+solution!(u32, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -6,6 +6,8 @@
 
 use std::collections::HashSet;
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Vec<Vec<&str>> {
   input
     .trim()
@@ -42,6 +44,8 @@ pub fn p2(input: &[Vec<&str>]) -> usize {
     .count()
 }
 
+solution!(Vec<Vec<&'a str>>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -3,7 +3,7 @@
 //! For part 1 the instruction is simply incremented, while for part 2 it is
 //! decremented if the value is greater than 2.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<i32> {
   input.iter_signed().collect()
@@ -33,6 +33,8 @@ pub fn p2(input: &[i32]) -> usize {
   count_jumps(input, |v| if v > 2 { -1 } else { 1 })
 }
 
+solution!(Vec<i32>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
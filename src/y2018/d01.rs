@@ -6,7 +6,7 @@
 
 use std::collections::HashSet;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<i32> {
   input.iter_signed().collect()
@@ -30,6 +30,8 @@ pub fn p2(input: &[i32]) -> i32 {
   unreachable!("No frequency repeated");
 }
 
+solution!(Vec<i32>, i32, i32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -8,6 +8,8 @@
 use core::str;
 use std::collections::HashSet;
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Vec<&[u8]> {
   input.trim().lines().map(str::as_bytes).collect()
 }
@@ -76,6 +78,8 @@ pub fn p2(input: &[&[u8]]) -> String {
   unreachable!("Should have found duplicate already");
 }
 
+solution!(Vec<&'a [u8]>, u32, String);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -10,7 +10,10 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::util::{parse::ParseOps, point::Point};
+use crate::{
+  puzzle::solution,
+  util::{parse::ParseOps, point::Point},
+};
 
 pub struct Claim {
   pub id: usize,
@@ -78,6 +81,8 @@ pub const fn p2(input: &Answers) -> usize {
   input.1
 }
 
+solution!(Answers, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -7,7 +7,7 @@
 
 use std::collections::HashMap;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 type Input = HashMap<usize, [u32; 60]>;
 
@@ -72,6 +72,8 @@ pub fn p2(input: &Input) -> usize {
   })
 }
 
+solution!(Input, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -13,6 +13,8 @@
 
 use itertools::Itertools;
 
+use crate::puzzle::solution;
+
 pub fn reduce(polymer: impl Iterator<Item = u8>) -> Vec<u8> {
   let mut inert = Vec::with_capacity(polymer.try_len().unwrap_or(10_000));
 
@@ -48,6 +50,8 @@ pub fn p2(input: &[u8]) -> usize {
     .unwrap()
 }
 
+solution!(Vec<u8>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -5,7 +5,7 @@
 //! less than or equal to 8, since any mass that requires less than 8 fuel will
 //! not require any additional fuel.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<u32> {
   input.iter_unsigned().collect()
@@ -37,6 +37,8 @@ pub fn p2(input: &[u32]) -> u32 {
     .sum()
 }
 
+solution!(Vec<u32>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
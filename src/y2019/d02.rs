@@ -23,7 +23,7 @@
 use std::cmp::Ordering::{Equal, Less};
 
 use super::intcode::IntcodeComputer;
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 type Input = [i32; 3];
 
 pub fn evaluate_program(noun: i32, verb: i32, program: &[i32]) -> i32 {
@@ -94,3 +94,5 @@ fn optimize(
 pub fn p2(input: &Input) -> i32 {
   optimize(input, 0, 99, 0, 99, 19_690_720).unwrap()
 }
+
+solution!(Input, i32, i32);
@@ -10,9 +10,12 @@ use std::{cmp::minmax, collections::BTreeMap};
 
 use itertools::Itertools;
 
-use crate::util::{
-  parse::ParseOps,
-  point::{Point, ORIGIN},
+use crate::{
+  puzzle::solution,
+  util::{
+    parse::ParseOps,
+    point::{Point, ORIGIN},
+  },
 };
 
 struct Segment {
@@ -134,6 +137,8 @@ pub const fn p2(input: &Answers) -> i32 {
   input.1
 }
 
+solution!(Answers, i32, i32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -9,7 +9,10 @@
 
 use itertools::Itertools;
 
-use crate::util::{parse::ParseOps, slice::SliceOps};
+use crate::{
+  puzzle::solution,
+  util::{parse::ParseOps, slice::SliceOps},
+};
 
 pub fn to_digits(mut num: u32) -> Vec<u32> {
   let mut digits = Vec::new();
@@ -89,6 +92,8 @@ pub fn p2(input: &[Vec<u32>]) -> usize {
   input.iter().filter(|&digits| criteria2(digits)).count()
 }
 
+solution!(Vec<Vec<u32>>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
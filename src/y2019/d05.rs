@@ -4,6 +4,7 @@
 //! for the first non-zero output value before the program halts.
 
 use crate::{
+  puzzle::solution,
   util::parse::ParseOps,
   y2019::intcode::{IntcodeComputer, State},
 };
@@ -36,3 +37,5 @@ pub fn p1(input: &[i32]) -> i32 {
 pub fn p2(input: &[i32]) -> i32 {
   run(input, 5)
 }
+
+solution!(Vec<i32>, i32, i32);
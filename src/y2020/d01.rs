@@ -9,7 +9,7 @@
 
 use std::collections::HashMap;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<u32> {
   input.iter_unsigned().collect()
@@ -59,6 +59,8 @@ pub fn p2(input: &[u32]) -> u32 {
   unreachable!("No solution found");
 }
 
+solution!(Vec<u32>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
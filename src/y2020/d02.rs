@@ -10,7 +10,7 @@
 //! letters, if any, at the specified positions are the same as the letter in
 //! the policy.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub struct PasswordPolicy<'a> {
   first: usize,
@@ -75,6 +75,8 @@ pub fn p2(input: &[PasswordPolicy<'_>]) -> usize {
     .count()
 }
 
+solution!(Vec<PasswordPolicy<'a>>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -4,9 +4,12 @@
 //! When we reach the horizontal limit, we wrap around to the beginning, while
 //! maintaining the vertical position.
 
-use crate::util::{
-  grid::Grid,
-  point::{Point, ORIGIN},
+use crate::{
+  puzzle::solution,
+  util::{
+    grid::Grid,
+    point::{Point, ORIGIN},
+  },
 };
 
 pub fn parse(input: &str) -> Grid<u8> {
@@ -41,6 +44,8 @@ pub fn p2(input: &Grid<u8>) -> u64 {
     .product()
 }
 
+solution!(Grid<u8>, u64, u64);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
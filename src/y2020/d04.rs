@@ -11,6 +11,8 @@
 
 use std::collections::HashMap;
 
+use crate::puzzle::solution;
+
 #[derive(Debug)]
 pub struct Passport<'a> {
   birth_year: u32,
@@ -103,6 +105,8 @@ pub fn p2(input: &[Passport]) -> usize {
   input.iter().filter(|p| p.is_valid()).count()
 }
 
+solution!(Vec<Passport<'a>>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
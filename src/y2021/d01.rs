@@ -3,7 +3,7 @@
 //! Part 1 and 2 are similar in that we are counting the number of windows
 //! (size=n + 1, n=1, 3) where the first element is less than the last element.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<u32> {
   input.iter_unsigned().collect()
@@ -24,6 +24,8 @@ pub fn p2(input: &[u32]) -> usize {
   count_increasing_windows(input, 3)
 }
 
+solution!(Vec<u32>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
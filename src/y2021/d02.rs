@@ -4,7 +4,10 @@
 //! Given each `Command`, we simply update the position according to the
 //! instructions.
 
-use crate::util::{parse::ParseOps, point::Point};
+use crate::{
+  puzzle::solution,
+  util::{parse::ParseOps, point::Point},
+};
 
 #[derive(Clone, Copy)]
 pub enum Command {
@@ -59,6 +62,8 @@ pub fn p2(input: &[Command]) -> u32 {
   (final_position.x * final_position.y).unsigned_abs()
 }
 
+solution!(Vec<Command>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
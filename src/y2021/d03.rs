@@ -9,6 +9,8 @@
 //!
 //! To get the least common bit, we simply invert the most common bit.
 
+use crate::puzzle::solution;
+
 type Input = (Vec<u16>, usize);
 pub fn parse(input: &str) -> Input {
   let lines = input.trim().lines().collect::<Vec<_>>();
@@ -78,6 +80,8 @@ pub fn p2((data, width): &Input) -> u32 {
   u32::from(oxy_rating_nums[0]) * u32::from(co2_rating_nums[0])
 }
 
+solution!(Input, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -9,7 +9,7 @@
 
 use std::array::from_fn;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 const BOARD_SIZE: usize = 25;
 const BINGO_CHECKS: [(usize, usize); 10] = [
@@ -84,6 +84,8 @@ pub fn p2(input: &[Board]) -> usize {
   input.iter().max_by_key(|board| board.turn).unwrap().score
 }
 
+solution!(Vec<Board>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
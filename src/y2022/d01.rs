@@ -6,7 +6,7 @@
 
 use std::{cmp::Reverse, collections::BinaryHeap};
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 type Answers = (u32, u32);
 
@@ -39,6 +39,8 @@ pub const fn p2(input: &Answers) -> u32 {
   input.1
 }
 
+solution!(Answers, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -9,6 +9,8 @@
 
 use std::cmp::Ordering::{self, Equal, Greater, Less};
 
+use crate::puzzle::solution;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Shape {
   Rock,
@@ -118,6 +120,8 @@ pub fn p2(input: &[Strategy]) -> u32 {
     .sum()
 }
 
+solution!(Vec<Strategy>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -6,6 +6,8 @@
 //! For part 2, we chunk lines into groups of 3 and then find the common
 //! letter.
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Vec<&str> {
   input.trim().lines().collect()
 }
@@ -64,6 +66,8 @@ pub fn p2(input: &[&str]) -> u32 {
     .sum()
 }
 
+solution!(Vec<&'a str>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
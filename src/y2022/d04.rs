@@ -6,7 +6,7 @@
 //! For part 2, we check if neither pair has any overlap and invert this condition using [DeMorgan's Law](https://en.wikipedia.org/wiki/De_Morgan%27s_laws).
 //! This only works under the assumption that the pairs are ordered.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 type Pair = [u32; 4];
 
@@ -28,6 +28,8 @@ pub fn p2(input: &[Pair]) -> usize {
     .count()
 }
 
+solution!(Vec<Pair>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
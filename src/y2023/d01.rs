@@ -8,7 +8,7 @@
 //! (for the first digit) or ends (for the last digit) with the spelling of a
 //! digit.
 
-use crate::util::parse::ParseByte;
+use crate::{puzzle::solution, util::parse::ParseByte};
 
 pub fn parse(input: &str) -> Vec<&str> {
   input.trim().lines().collect()
@@ -84,6 +84,8 @@ pub fn p2(input: &[&str]) -> usize {
     .sum()
 }
 
+solution!(Vec<&'a str>, u32, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
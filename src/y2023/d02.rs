@@ -3,7 +3,7 @@
 //! For each game we compute the maximum of each color of cubes that could have
 //! been in the bag.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 #[derive(Default, Debug)]
 pub struct Game {
@@ -52,6 +52,8 @@ pub fn p2(input: &[Game]) -> u32 {
     .sum()
 }
 
+solution!(Vec<Game>, usize, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
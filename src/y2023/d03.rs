@@ -19,7 +19,10 @@
 //!
 //! > This solution was heavily inspired by [`maneatingape`'s solution](https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2023/day03.rs).
 
-use crate::util::{grid::Grid, parse::ParseByte, point::Point};
+use crate::{
+  puzzle::solution,
+  util::{grid::Grid, parse::ParseByte, point::Point},
+};
 
 pub struct Schematic {
   grid: Grid<u8>,
@@ -121,6 +124,8 @@ pub fn p2(input: &Schematic) -> u32 {
   result
 }
 
+solution!(Schematic, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
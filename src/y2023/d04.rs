@@ -7,7 +7,7 @@
 //! Whenever we encounter a card with a `n` winning numbers, we add, for every
 //! copy of this card, a copy of the next `n` cards.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> Vec<usize> {
   input
@@ -38,6 +38,8 @@ pub fn p2(input: &[usize]) -> usize {
   copies.iter().sum()
 }
 
+solution!(Vec<usize>, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
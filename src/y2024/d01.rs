@@ -5,7 +5,7 @@
 
 use itertools::Itertools;
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 type Input = (Vec<u32>, Vec<u32>);
 
@@ -34,6 +34,8 @@ pub fn p2(input: &Input) -> usize {
     .sum()
 }
 
+solution!(Input, u32, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -12,7 +12,7 @@
 //! safe report. This is done by discarding the change between that level and
 //! its neighbors, and adding the change between those neighbors.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 type Answers = (usize, usize);
 
@@ -81,6 +81,8 @@ pub const fn p2(input: &Answers) -> usize {
   input.1
 }
 
+solution!(Answers, usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
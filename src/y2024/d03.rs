@@ -8,6 +8,8 @@
 //!
 //! The other instructions are parsed by simply matching the string (as bytes).
 
+use crate::puzzle::solution;
+
 type Answers = (u32, u32);
 
 fn parse_number(memory: &[u8], index: &mut usize) -> u32 {
@@ -90,6 +92,8 @@ pub const fn p2(input: &Answers) -> u32 {
   input.1
 }
 
+solution!(Answers, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -25,9 +25,12 @@
 
 use lazy_static::lazy_static;
 
-use crate::util::{
-  grid::Grid,
-  point::{Point, DOWN, LEFT, RIGHT},
+use crate::{
+  puzzle::solution,
+  util::{
+    grid::Grid,
+    point::{Point, DOWN, LEFT, RIGHT},
+  },
 };
 
 lazy_static! {
@@ -138,6 +141,8 @@ fn scan_line(
   result
 }
 
+solution!(Grid<u8>, u32, u32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
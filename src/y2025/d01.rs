@@ -8,7 +8,7 @@
 //! - for left turns, we invert the dial and follow the logic if it were a right
 //!   turn
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn parse(input: &str) -> (i32, i32) {
   let direction = input.bytes().filter(|&b| b.is_ascii_uppercase());
@@ -43,6 +43,8 @@ pub const fn p2(input: &(i32, i32)) -> i32 {
   input.1
 }
 
+solution!((i32, i32), i32, i32);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -13,7 +13,7 @@
 //! prime length digits and filter out the cases when we count a number in both
 //! cases.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 /// Defines the arithmetic progression parameters for a specific repeating
 /// pattern.
@@ -148,6 +148,8 @@ pub const fn p2(input: &(u64, u64)) -> u64 {
   input.1
 }
 
+solution!((u64, u64), u64, u64);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -15,6 +15,8 @@
 
 use std::mem::replace;
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Vec<&str> {
   input.lines().collect()
 }
@@ -60,6 +62,8 @@ pub fn p2(input: &[&str]) -> u64 {
   total_joltage::<12>(input)
 }
 
+solution!(Vec<&'a str>, u64, u64);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -14,7 +14,10 @@
 //! each point that has/had a roll, and keep removing rolls until there are none
 //! that can be removed.
 
-use crate::util::{grid::Grid, point::Point};
+use crate::{
+  puzzle::solution,
+  util::{grid::Grid, point::Point},
+};
 
 #[allow(clippy::cast_possible_truncation)]
 pub fn parse(input: &str) -> (usize, usize) {
@@ -73,6 +76,8 @@ pub const fn p2(input: &(usize, usize)) -> usize {
   input.1
 }
 
+solution!((usize, usize), usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
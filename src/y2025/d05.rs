@@ -6,7 +6,7 @@
 //! ranges. For part 2, we count the number of IDs in each range, after having
 //! merged them.
 
-use crate::util::parse::ParseOps;
+use crate::{puzzle::solution, util::parse::ParseOps};
 
 pub fn merge(intervals: &[[u64; 2]]) -> Vec<[u64; 2]> {
   if intervals.is_empty() {
@@ -73,6 +73,8 @@ pub fn p2(input: &(Vec<[u64; 2]>, Vec<u64>)) -> u64 {
     .fold(0, |acc, &range| acc + (range[1] - range[0]) + 1)
 }
 
+solution!((Vec<[u64; 2]>, Vec<u64>), usize, u64);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
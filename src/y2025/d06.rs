@@ -7,7 +7,10 @@
 //! - Then, we move either horizontally (part 1) or vertically (part 2) to
 //!   construct the operands
 
-use crate::util::{grid::Grid, point::Point};
+use crate::{
+  puzzle::solution,
+  util::{grid::Grid, point::Point},
+};
 
 pub fn parse(input: &str) -> Grid<u8> {
   Grid::parse(input)
@@ -68,6 +71,8 @@ pub fn p2(input: &Grid<u8>) -> u64 {
   operate(input, true)
 }
 
+solution!(Grid<u8>, u64, u64);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -10,6 +10,8 @@
 //! For part 1 we count the number of encounters and for part 2 we sum the
 //! number of timelines in all spaces.
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> (usize, usize) {
   let manifold = input.trim().lines().map(str::as_bytes).collect::<Vec<_>>();
   let width = manifold[0].len();
@@ -56,6 +58,8 @@ pub const fn p2(input: &(usize, usize)) -> usize {
   input.1
 }
 
+solution!((usize, usize), usize, usize);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -22,9 +22,12 @@
 
 use std::collections::BinaryHeap;
 
-use crate::util::{
-  parse::ParseOps,
-  thread::{spawn_parallel_iterator, ParIter},
+use crate::{
+  puzzle::solution,
+  util::{
+    parse::ParseOps,
+    thread::{spawn_parallel_iterator, ParIter},
+  },
 };
 
 type Box = [usize; 3];
@@ -156,6 +159,8 @@ pub const fn p2(input: &(usize, usize)) -> usize {
   input.1
 }
 
+solution!((usize, usize), usize, usize, multithreaded);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -2,6 +2,8 @@
 //!
 //! Use this docstring to explain the problem and how it is solved.
 
+use crate::puzzle::solution;
+
 pub fn parse(input: &str) -> Input {
   unimplemented!()
 }
@@ -14,6 +16,8 @@ pub fn p2(input: &Input) -> OutputP2 {
   unimplemented!()
 }
 
+solution!(Input, OutputP1, OutputP2);
+
 #[cfg(test)]
 mod tests {
   use rstest::rstest;